@@ -0,0 +1,67 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    /// The pair in which the swap will be performed
+    #[account(
+        mut,
+        has_one = reserve_x,
+        has_one = reserve_y,
+        has_one = token_x_mint,
+        has_one = token_y_mint,
+        has_one = oracle
+    )]
+    pub lb_pair: AccountLoader<'info, LbPair>,
+
+    /// CHECK: Bitmap extension of the pair, the DLMM program id when the pair has none
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+
+    /// The reserve of token X
+    #[account(mut)]
+    pub reserve_x: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The reserve of token Y
+    #[account(mut)]
+    pub reserve_y: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account for input token
+    #[account(mut)]
+    pub user_token_in: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account for output token
+    #[account(mut)]
+    pub user_token_out: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_x_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_y_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Oracle of the pair
+    #[account(mut)]
+    pub oracle: UncheckedAccount<'info>,
+
+    /// Token account receiving the host fee, the DLMM program id for no host fee
+    #[account(mut)]
+    pub host_fee_in: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The user performing the swap
+    pub user: Signer<'info>,
+
+    /// SPL program of token X
+    pub token_x_program: Interface<'info, TokenInterface>,
+
+    /// SPL program of token Y
+    pub token_y_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Event authority PDA of the DLMM program, `[EVENT_AUTHORITY_SEED]`
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// CHECK: DLMM program
+    pub program: UncheckedAccount<'info>,
+    // remaining accounts
+    // bin_array_account_1
+    // bin_array_account_2
+    // bin_array_account_...
+}
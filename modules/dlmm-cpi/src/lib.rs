@@ -0,0 +1,52 @@
+#![allow(unused)]
+
+pub mod context;
+pub mod states;
+
+pub use context::*;
+
+use anchor_lang::prelude::*;
+
+// DLMM is deployed at the same address on devnet and mainnet
+#[cfg(feature = "devnet")]
+declare_id!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+#[cfg(not(feature = "devnet"))]
+declare_id!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+#[program]
+pub mod lb_clmm {
+
+    use super::*;
+
+    /// Swaps an exact input amount across the bins of a pair
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_in` - Amount of input token to swap
+    /// * `min_amount_out` - For slippage check
+    ///
+    pub fn swap<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Swaps for an exact output amount across the bins of a pair
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `max_in_amount` - For slippage check
+    /// * `out_amount` - Amount of output token to receive
+    ///
+    pub fn swap_exact_out<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Swap<'info>>,
+        max_in_amount: u64,
+        out_amount: u64,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+
+pub const BIN_ARRAY_SEED: &str = "bin_array";
+pub const ORACLE_SEED: &str = "oracle";
+pub const BIN_ARRAY_BITMAP_SEED: &str = "bitmap";
+// Seed of the PDA emitting the program events
+pub const EVENT_AUTHORITY_SEED: &str = "__event_authority";
+
+// Number of bins in a BinArray
+pub const MAX_BIN_PER_ARRAY: usize = 70;
+// Number of rewards Token
+pub const NUM_REWARDS: usize = 2;
+
+/// Parameters of the pair set at initialization
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct StaticParameters {
+    pub base_factor: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub variable_fee_control: u32,
+    pub max_volatility_accumulator: u32,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub protocol_share: u16,
+    pub base_fee_power_factor: u8,
+    pub padding: [u8; 5],
+}
+
+impl StaticParameters {
+    pub const LEN: usize = 2 + 2 + 2 + 2 + 4 + 4 + 4 + 4 + 2 + 1 + 5;
+}
+
+/// Parameters of the pair updated by swaps
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct VariableParameters {
+    pub volatility_accumulator: u32,
+    pub volatility_reference: u32,
+    pub index_reference: i32,
+    pub padding: [u8; 4],
+    pub last_update_timestamp: i64,
+    pub padding1: [u8; 8],
+}
+
+impl VariableParameters {
+    pub const LEN: usize = 4 + 4 + 4 + 4 + 8 + 8;
+}
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct ProtocolFee {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+/// State of reward
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct RewardInfo {
+    /// Reward token mint.
+    pub mint: Pubkey,
+    /// Reward vault token account.
+    pub vault: Pubkey,
+    /// The account funding the reward
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_bin: u64,
+}
+
+impl RewardInfo {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 16 + 8 + 8;
+}
+
+/// The pair state
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    pub v_parameters: VariableParameters,
+    /// Bump to identify PDA
+    pub bump_seed: [u8; 1],
+    pub bin_step_seed: [u8; 2],
+    pub pair_type: u8,
+    /// The current bin of the pair
+    pub active_id: i32,
+    /// Price increment between bins, in basis points
+    pub bin_step: u16,
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub padding0: u8,
+
+    /// Token pair of the pair
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+
+    /// Token pair reserves
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+
+    pub protocol_fee: ProtocolFee,
+    pub padding1: [u8; 32],
+    pub reward_infos: [RewardInfo; NUM_REWARDS],
+
+    /// oracle account key
+    pub oracle: Pubkey,
+    /// Initialized bin arrays around the active bin
+    pub bin_array_bitmap: [u64; 16],
+    pub last_updated_at: i64,
+    pub padding2: [u8; 32],
+    pub pre_activation_swap_address: Pubkey,
+    pub base_key: Pubkey,
+    pub activation_point: u64,
+    pub pre_activation_duration: u64,
+    pub padding3: [u8; 8],
+    pub padding4: u64,
+    pub creator: Pubkey,
+    // Unused bytes for future upgrades.
+    pub reserved: [u8; 24],
+}
+
+impl LbPair {
+    pub const LEN: usize = 8
+        + StaticParameters::LEN
+        + VariableParameters::LEN
+        + 1
+        + 2
+        + 1
+        + 4
+        + 2
+        + 1
+        + 1
+        + 2
+        + 1
+        + 1
+        + 32 * 4
+        + 16
+        + 32
+        + RewardInfo::LEN * NUM_REWARDS
+        + 32
+        + 8 * 16
+        + 8
+        + 32
+        + 32
+        + 32
+        + 8
+        + 8
+        + 8
+        + 8
+        + 32
+        + 24;
+}
+
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct BinArray {
+    pub index: i64,
+    pub version: u8,
+    pub padding: [u8; 7],
+    pub lb_pair: Pubkey,
+    pub bins: [Bin; MAX_BIN_PER_ARRAY],
+}
+
+impl BinArray {
+    pub const LEN: usize = 8 + 8 + 1 + 7 + 32 + Bin::LEN * MAX_BIN_PER_ARRAY;
+}
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct Bin {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    /// Q64.64 price of the bin
+    pub price: u128,
+    pub liquidity_supply: u128,
+    pub reward_per_token_stored: [u128; NUM_REWARDS],
+    pub fee_amount_x_per_token_stored: u128,
+    pub fee_amount_y_per_token_stored: u128,
+    pub amount_x_in: u128,
+    pub amount_y_in: u128,
+}
+
+impl Bin {
+    pub const LEN: usize = 8 + 8 + 16 + 16 + 16 * NUM_REWARDS + 16 + 16 + 16 + 16;
+}
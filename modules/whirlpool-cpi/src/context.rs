@@ -0,0 +1,111 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    /// SPL program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// The user performing the swap
+    pub token_authority: Signer<'info>,
+
+    /// The pool in which the swap will be performed
+    #[account(mut)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    /// The user token account for token A
+    #[account(mut, constraint = token_owner_account_a.mint == whirlpool.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token A
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account for token B
+    #[account(mut, constraint = token_owner_account_b.mint == whirlpool.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token B
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Tick arrays crossed by the swap, starting with the one of the current tick
+    #[account(mut, constraint = tick_array_0.load()?.whirlpool == whirlpool.key())]
+    pub tick_array_0: AccountLoader<'info, TickArray>,
+
+    #[account(mut, constraint = tick_array_1.load()?.whirlpool == whirlpool.key())]
+    pub tick_array_1: AccountLoader<'info, TickArray>,
+
+    #[account(mut, constraint = tick_array_2.load()?.whirlpool == whirlpool.key())]
+    pub tick_array_2: AccountLoader<'info, TickArray>,
+
+    /// CHECK: Oracle PDA of the pool, `[ORACLE_SEED, whirlpool]`
+    #[account(mut)]
+    pub oracle: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SwapV2<'info> {
+    /// SPL program of token A
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+
+    /// SPL program of token B
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+
+    /// memo program
+    pub memo_program: Program<'info, Memo>,
+
+    /// The user performing the swap
+    pub token_authority: Signer<'info>,
+
+    /// The pool in which the swap will be performed
+    #[account(mut)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(address = whirlpool.token_mint_a)]
+    pub token_mint_a: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = whirlpool.token_mint_b)]
+    pub token_mint_b: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The user token account for token A
+    #[account(mut, constraint = token_owner_account_a.mint == whirlpool.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token A
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account for token B
+    #[account(mut, constraint = token_owner_account_b.mint == whirlpool.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for token B
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Tick arrays crossed by the swap, checked by the Whirlpool program
+    #[account(mut)]
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    /// CHECK: checked by the Whirlpool program
+    #[account(mut)]
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    /// CHECK: checked by the Whirlpool program
+    #[account(mut)]
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    /// CHECK: Oracle PDA of the pool, `[ORACLE_SEED, whirlpool]`
+    #[account(mut)]
+    pub oracle: UncheckedAccount<'info>,
+    // remaining accounts, described by `remaining_accounts_info`
+    // transfer hook accounts of token A
+    // transfer hook accounts of token B
+    // supplemental tick arrays
+}
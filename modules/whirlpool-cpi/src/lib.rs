@@ -0,0 +1,67 @@
+#![allow(unused)]
+
+pub mod context;
+pub mod states;
+
+pub use context::*;
+pub use states::{AccountsType, RemainingAccountsInfo, RemainingAccountsSlice};
+
+use anchor_lang::prelude::*;
+
+// Whirlpool is deployed at the same address on devnet and mainnet
+#[cfg(feature = "devnet")]
+declare_id!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+#[cfg(not(feature = "devnet"))]
+declare_id!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+#[program]
+pub mod whirlpool {
+
+    use super::*;
+
+    /// Performs a swap in a single pool
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount` - Arranged in pairs with other_amount_threshold. (amount_in, amount_out_minimum) or (amount_out, amount_in_maximum)
+    /// * `other_amount_threshold` - For slippage check
+    /// * `sqrt_price_limit` - The Q64.64 sqrt price √P limit, zero to use the bound of the swap direction
+    /// * `amount_specified_is_input` - swap base input or swap base output
+    /// * `a_to_b` - The direction of the swap, true to swap token A for token B
+    ///
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Performs a swap in a single pool, support token program 2022
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount` - Arranged in pairs with other_amount_threshold. (amount_in, amount_out_minimum) or (amount_out, amount_in_maximum)
+    /// * `other_amount_threshold` - For slippage check
+    /// * `sqrt_price_limit` - The Q64.64 sqrt price √P limit, zero to use the bound of the swap direction
+    /// * `amount_specified_is_input` - swap base input or swap base output
+    /// * `a_to_b` - The direction of the swap, true to swap token A for token B
+    /// * `remaining_accounts_info` - Transfer hook and supplemental tick array accounts passed as remaining accounts
+    ///
+    pub fn swap_v2<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapV2<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
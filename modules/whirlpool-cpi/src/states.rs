@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+pub const WHIRLPOOL_SEED: &str = "whirlpool";
+pub const TICK_ARRAY_SEED: &str = "tick_array";
+// Seed to derive the oracle account of a pool
+pub const ORACLE_SEED: &str = "oracle";
+
+// Number of rewards Token
+pub const NUM_REWARDS: usize = 3;
+pub const TICK_ARRAY_SIZE_USIZE: usize = 88;
+pub const TICK_ARRAY_SIZE: i32 = 88;
+
+/// Bounds of the Q64.64 sqrt price of a pool
+pub const MIN_SQRT_PRICE_X64: u128 = 4295048016;
+pub const MAX_SQRT_PRICE_X64: u128 = 79226673515401279992447579055;
+
+/// The pool state
+///
+/// PDA of `[WHIRLPOOL_SEED, whirlpools_config, token_mint_a, token_mint_b, tick_spacing]`
+///
+#[account]
+#[derive(Default, Debug)]
+pub struct Whirlpool {
+    // Which config the pool belongs
+    pub whirlpools_config: Pubkey,
+    /// Bump to identify PDA
+    pub whirlpool_bump: [u8; 1],
+
+    /// The minimum number of ticks between initialized ticks
+    pub tick_spacing: u16,
+    pub tick_spacing_seed: [u8; 2],
+
+    /// The trade fee, denominated in hundredths of a bip (10^-6)
+    pub fee_rate: u16,
+    /// Portion of the trade fee taken as protocol fee, denominated in basis points
+    pub protocol_fee_rate: u16,
+
+    /// The currently in range liquidity available to the pool.
+    pub liquidity: u128,
+    /// The current price of the pool as a sqrt(token_b/token_a) Q64.64 value
+    pub sqrt_price: u128,
+    /// The current tick of the pool
+    pub tick_current_index: i32,
+
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+
+    /// Token pair of the pool, where token_mint_a address < token_mint_b address
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    /// Q64.64 fee growth of token A per unit of liquidity
+    pub fee_growth_global_a: u128,
+
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    /// Q64.64 fee growth of token B per unit of liquidity
+    pub fee_growth_global_b: u128,
+
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
+}
+
+impl Whirlpool {
+    pub const LEN: usize = 8
+        + 32
+        + 1
+        + 2
+        + 2
+        + 2
+        + 2
+        + 16
+        + 16
+        + 4
+        + 8
+        + 8
+        + 32
+        + 32
+        + 16
+        + 32
+        + 32
+        + 16
+        + 8
+        + WhirlpoolRewardInfo::LEN * NUM_REWARDS;
+}
+
+/// State of reward
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq, Eq)]
+pub struct WhirlpoolRewardInfo {
+    /// Reward token mint.
+    pub mint: Pubkey,
+    /// Reward vault token account.
+    pub vault: Pubkey,
+    /// The owner that has permission to set reward param
+    pub authority: Pubkey,
+    /// Q64.64 number indicates how many tokens per second are earned per unit of liquidity.
+    pub emissions_per_second_x64: u128,
+    /// Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward
+    /// emissions were turned on.
+    pub growth_global_x64: u128,
+}
+
+impl WhirlpoolRewardInfo {
+    pub const LEN: usize = 32 + 32 + 32 + 16 + 16;
+}
+
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    pub ticks: [Tick; TICK_ARRAY_SIZE_USIZE],
+    pub whirlpool: Pubkey,
+}
+
+impl TickArray {
+    pub const LEN: usize = 8 + 4 + Tick::LEN * TICK_ARRAY_SIZE_USIZE + 32;
+}
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct Tick {
+    pub initialized: bool,
+    /// Amount of net liquidity added (subtracted) when tick is crossed from left to right (right to left)
+    pub liquidity_net: i128,
+    /// The total position liquidity that references this tick
+    pub liquidity_gross: u128,
+
+    /// Fee growth per unit of liquidity on the _other_ side of this tick (relative to the current tick)
+    /// only has relative meaning, not absolute — the value depends on when the tick is initialized
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+
+    // Reward growth per unit of liquidity like fee, array of Q64.64
+    pub reward_growths_outside: [u128; NUM_REWARDS],
+}
+
+impl Tick {
+    pub const LEN: usize = 1 + 16 + 16 + 16 + 16 + 16 * NUM_REWARDS;
+}
+
+/// Kind of the accounts of a slice of the remaining accounts of `swap_v2`
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub enum AccountsType {
+    TransferHookA,
+    TransferHookB,
+    TransferHookReward,
+    TransferHookInput,
+    TransferHookIntermediate,
+    TransferHookOutput,
+    SupplementalTickArrays,
+    SupplementalTickArraysOne,
+    SupplementalTickArraysTwo,
+}
+
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub struct RemainingAccountsSlice {
+    pub accounts_type: AccountsType,
+    pub length: u8,
+}
+
+/// Layout of the remaining accounts of `swap_v2`, in order
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq)]
+pub struct RemainingAccountsInfo {
+    pub slices: Vec<RemainingAccountsSlice>,
+}
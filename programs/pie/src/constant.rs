@@ -5,12 +5,36 @@ pub const USER_FUND: &[u8] = b"user_fund";
 pub const USER_BALANCE: &[u8] = b"user_balance";
 pub const BASKET_MINT: &[u8] = b"basket_mint";
 pub const BASKET_CONFIG: &[u8] = b"basket_config";
+pub const PRICE_FEED: &[u8] = b"price_feed";
+pub const ALLOWLIST_ENTRY: &[u8] = b"allowlist_entry";
+pub const CREATOR_WHITELIST: &[u8] = b"creator_whitelist";
+pub const TOKEN_REGISTRY: &[u8] = b"token_registry";
+pub const USER_MINT_RECORD: &[u8] = b"user_mint_record";
 
 pub const BASKET_DECIMALS: u8 = 6;
 pub const SYS_DECIMALS: u64 = 1_000_000;
 pub const MAX_COMPONENTS: u8 = 15;
 pub const MAX_BALANCES: u16 = 1000; // @TODO: need to test the max balances
+pub const MAX_WHITELISTED_CREATORS: usize = 50;
 pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 pub const JUPITER_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
 
 pub const BASIS_POINTS: u64 = 10_000;
+pub const MAX_MANAGEMENT_FEE_BP: u64 = 1_000; // 10% per year
+pub const MAX_PERFORMANCE_FEE_BP: u64 = 3_000; // 30% of the gain above the high-water mark
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+pub const MIN_TWAP_WINDOW_SECS: u32 = 300;
+
+// Token registry risk flags
+pub const RISK_FLAG_FREEZE_AUTHORITY: u64 = 1 << 0;
+pub const RISK_FLAG_MINT_AUTHORITY: u64 = 1 << 1;
+pub const RISK_FLAG_TOKEN_2022_EXTENSIONS: u64 = 1 << 2;
+pub const RISK_FLAG_LOW_LIQUIDITY: u64 = 1 << 3;
+
+// Pause flags of the program state and basket configs
+pub const PAUSE_MINT: u64 = 1 << 0;
+pub const PAUSE_REDEEM: u64 = 1 << 1;
+pub const PAUSE_SWAP: u64 = 1 << 2;
+pub const PAUSE_REBALANCE: u64 = 1 << 3;
+pub const PAUSE_ALL: u64 = PAUSE_MINT | PAUSE_REDEEM | PAUSE_SWAP | PAUSE_REBALANCE;
@@ -82,4 +82,85 @@ pub enum PieError {
 
     #[msg("Component change not allowed basket")]
     ComponentChangeNotAllowedBasket,
+
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("SOL amount is below the minimum")]
+    InsufficientSolAmount,
+
+    #[msg("Redeemed component amount is below the minimum")]
+    InsufficientRedeemAmount,
+
+    #[msg("Invalid min amounts out")]
+    InvalidMinAmountsOut,
+
+    #[msg("Invalid target weights")]
+    InvalidTargetWeights,
+
+    #[msg("Component weights are outside the target tolerance")]
+    TargetWeightsNotMet,
+
+    #[msg("Invalid price feed")]
+    InvalidPriceFeed,
+
+    #[msg("Stale price")]
+    StalePrice,
+
+    #[msg("Invalid price")]
+    InvalidPrice,
+
+    #[msg("Price confidence interval is too wide")]
+    PriceConfidenceTooWide,
+
+    #[msg("Rebalance slippage exceeded")]
+    RebalanceSlippageExceeded,
+
+    #[msg("Rebalance loss budget exceeded")]
+    RebalanceLossBudgetExceeded,
+
+    #[msg("TWAP window is too short")]
+    TwapWindowTooShort,
+
+    #[msg("Basket max supply exceeded")]
+    MaxSupplyExceeded,
+
+    #[msg("Basket max holding per wallet exceeded")]
+    MaxHoldingExceeded,
+
+    #[msg("User is not allowlisted")]
+    NotAllowlisted,
+
+    #[msg("Invalid allowlist")]
+    InvalidAllowlist,
+
+    #[msg("Creator is not whitelisted")]
+    CreatorNotWhitelisted,
+
+    #[msg("Token is not approved in the token registry")]
+    TokenNotApproved,
+
+    #[msg("Operation is paused")]
+    Paused,
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Invalid Jupiter route")]
+    InvalidJupiterRoute,
+
+    #[msg("Swap output is below the minimum")]
+    SwapAmountOutTooLow,
+
+    #[msg("Swap input is above the maximum")]
+    SwapAmountInTooHigh,
+
+    #[msg("Invalid swap program")]
+    InvalidSwapProgram,
+
+    #[msg("Invalid swap accounts")]
+    InvalidSwapAccounts,
+
+    #[msg("Minted basket amount is below the minimum mint amount")]
+    MintSlippageExceeded,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{CREATOR_WHITELIST, PROGRAM_STATE},
+    error::PieError,
+    states::CreatorWhitelist,
+    ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct AddWhitelistedCreatorContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CreatorWhitelist::INIT_SPACE,
+        seeds = [CREATOR_WHITELIST],
+        bump
+    )]
+    pub creator_whitelist: Box<Account<'info, CreatorWhitelist>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AddWhitelistedCreatorEvent {
+    pub creator: Pubkey,
+}
+
+pub fn add_whitelisted_creator(
+    ctx: Context<AddWhitelistedCreatorContext>,
+    creator: Pubkey,
+) -> Result<()> {
+    let creator_whitelist = &mut ctx.accounts.creator_whitelist;
+    creator_whitelist.bump = ctx.bumps.creator_whitelist;
+    creator_whitelist.add_creator(creator)?;
+
+    emit!(AddWhitelistedCreatorEvent { creator });
+
+    Ok(())
+}
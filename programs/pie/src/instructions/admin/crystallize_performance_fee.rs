@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, MintTo, Token, TokenAccount},
+    token_interface::Mint,
+};
+
+use crate::{
+    constant::{BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE},
+    error::PieError,
+    states::BasketState,
+    utils::{
+        calculate_basket_nav, calculate_performance_fee_amount, load_component_prices,
+        load_price, split_price_accounts, Calculator, VALUE_DECIMALS,
+    },
+    BasketConfig, ProgramState,
+};
+
+/// Decimals of SOL, in which the high-water mark is kept
+const SOL_DECIMALS: i32 = 9;
+
+#[derive(Accounts)]
+pub struct CrystallizePerformanceFeeContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Creator wallet that receives the fee
+    #[account(address = basket_config.creator)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = basket_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // remaining accounts:
+    // price feed accounts of the native mint (see `price_accounts_len`)
+    // for each component in `basket_config.components` order, price feed accounts of the component mint
+}
+
+#[event]
+pub struct CrystallizePerformanceFeeEvent {
+    pub basket_id: u64,
+    pub basket_mint: Pubkey,
+    pub nav_per_token: u64,
+    pub old_high_water_mark: u64,
+    pub new_high_water_mark: u64,
+    pub creator_fee: u64,
+}
+
+/// Crystallizes the performance fee of a basket against its high-water mark.
+/// The NAV of one basket token (10^BASKET_DECIMALS base units) is computed in lamports from
+/// the component price feeds and the SOL price feed. The first call only records the
+/// high-water mark.
+pub fn crystallize_performance_fee<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CrystallizePerformanceFeeContext<'info>>,
+) -> Result<()> {
+    let basket_config = &mut ctx.accounts.basket_config;
+    require!(
+        basket_config.state == BasketState::Active,
+        PieError::OnlyDefaultState
+    );
+
+    let total_supply = ctx.accounts.basket_mint.supply;
+    // The NAV per token of an empty basket is undefined
+    require!(total_supply > 0, PieError::InvalidAmount);
+    let old_high_water_mark = basket_config.high_water_mark;
+
+    let now = Clock::get()?.unix_timestamp;
+    let (sol_price_accounts, component_price_accounts) =
+        split_price_accounts(ctx.remaining_accounts)?;
+    let sol_price = load_price(&NATIVE_MINT, sol_price_accounts, now)?;
+    let prices = load_component_prices(&basket_config.components, component_price_accounts, now)?;
+    let nav = calculate_basket_nav(
+        &basket_config.components,
+        &prices,
+        total_supply,
+        Some(&sol_price),
+    )?;
+    let nav_per_token = Calculator::to_u64(
+        nav.nav_per_token / 10u128.pow((VALUE_DECIMALS - SOL_DECIMALS) as u32),
+    )?;
+    require!(nav_per_token > 0, PieError::InvalidPrice);
+
+    let (fee_amount, new_high_water_mark) = if old_high_water_mark == 0 {
+        (0, nav_per_token)
+    } else {
+        calculate_performance_fee_amount(
+            total_supply,
+            nav_per_token,
+            old_high_water_mark,
+            basket_config.performance_fee_bp,
+        )?
+    };
+
+    basket_config.high_water_mark = new_high_water_mark;
+
+    if fee_amount > 0 {
+        let signer: &[&[&[u8]]] = &[&[
+            BASKET_CONFIG,
+            &basket_config.id.to_be_bytes(),
+            &[basket_config.bump],
+        ]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.basket_mint.to_account_info(),
+                    to: ctx.accounts.creator_basket_token_account.to_account_info(),
+                    authority: basket_config.to_account_info(),
+                },
+                signer,
+            ),
+            fee_amount,
+        )?;
+
+        basket_config.dilute_components(
+            total_supply,
+            total_supply.checked_add(fee_amount).unwrap(),
+        )?;
+    }
+
+    emit!(CrystallizePerformanceFeeEvent {
+        basket_id: basket_config.id,
+        basket_mint: basket_config.mint,
+        nav_per_token,
+        old_high_water_mark,
+        new_high_water_mark,
+        creator_fee: fee_amount,
+    });
+
+    Ok(())
+}
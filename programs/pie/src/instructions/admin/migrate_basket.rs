@@ -8,8 +8,8 @@ use anchor_lang::prelude::{
 use crate::{
     constant::PROGRAM_STATE,
     error::PieError,
-    states::{BasketComponent, BasketState, RebalanceType},
-    BasketConfig, ProgramState,
+    states::{BasketState, RebalanceType},
+    ProgramState,
 };
 
 #[derive(Accounts)]
@@ -43,7 +43,7 @@ pub fn migrate_basket(ctx: Context<MigrateBasketContext>) -> Result<()> {
     // @TODO: Set the correct number for Production
     require!(old.id < 27, PieError::InvalidBasketId);
 
-    let mut upgraded = BasketConfig {
+    let mut upgraded = BasketConfigV2 {
         bump: old.bump,
         id: old.id,
         version: 2,
@@ -62,6 +62,12 @@ pub fn migrate_basket(ctx: Context<MigrateBasketContext>) -> Result<()> {
     Ok(())
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub struct BasketComponentV1 {
+    pub mint: Pubkey,
+    pub quantity_in_sys_decimal: u128,
+}
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct BasketConfigV1 {
     pub bump: u8,
@@ -70,7 +76,22 @@ pub struct BasketConfigV1 {
     pub rebalancer: Pubkey,
     pub mint: Pubkey,
     pub is_rebalancing: bool,
-    pub components: Vec<BasketComponent>,
+    pub components: Vec<BasketComponentV1>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct BasketConfigV2 {
+    pub bump: u8,
+    pub id: u64,
+    pub version: u8,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub rebalancer: Pubkey,
+    pub state: BasketState,
+    pub rebalance_type: RebalanceType,
+    pub creator_fee_bp: u64,
+    pub components: Vec<BasketComponentV1>,
+    pub reserved: [u64; 10],
 }
 
 #[cfg(test)]
@@ -137,7 +158,7 @@ mod tests {
 
         let old = BasketConfigV1::deserialize_reader(&mut cursor).unwrap();
 
-        let mut upgraded = BasketConfig {
+        let mut upgraded = BasketConfigV2 {
             bump: old.bump,
             version: 2,
             id: old.id,
@@ -153,7 +174,7 @@ mod tests {
 
         upgraded.serialize(&mut &mut raw_data[8..]).unwrap();
 
-        let new = BasketConfig::deserialize(&mut &raw_data[8..]).unwrap();
+        let new = BasketConfigV2::deserialize(&mut &raw_data[8..]).unwrap();
 
         assert_eq!(new.id, 3);
         assert_eq!(new.creator, old.creator);
@@ -0,0 +1,206 @@
+use anchor_lang::{
+    prelude::{borsh::BorshDeserialize, *},
+    Discriminator,
+};
+
+use crate::{
+    constant::PROGRAM_STATE,
+    error::PieError,
+    instructions::BasketConfigV2,
+    states::{AllowlistMode, BasketComponent},
+    utils::read_component_mint,
+    BasketConfig, ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBasketV5Context<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: we are manually deserializing it
+    #[account(mut, owner = crate::ID)]
+    pub basket_config: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts: the mint of each component, in `components` order
+}
+
+/// Migrates a V2 basket config to V5. The account is reallocated to the current basket
+/// config size, with additional rent paid by the admin. The decimals of each component are
+/// read from its mint, and the fields added since V2 are initialized as follows:
+/// - `management_fee_bp` and `performance_fee_bp` are 0: no fees, and management fees
+///   accrue from the first accrual after the migration
+/// - every `target_weight_bp` and `weight_tolerance_bp` are 0: the basket stays quantity based
+/// - rebalance limits are 0: rebalancing swaps are not bounded by oracle prices
+/// - `max_supply` and `max_holding_per_wallet` are 0: no supply or per-wallet cap
+/// - `allowlist_mode` is `None` and `allowlist_root` is zero: anyone can mint
+/// - `permissionless_components` is false for every basket: components added by a rebalance
+///   need an approved token registry entry, as only new baskets can opt out at creation
+/// - `pause_flags` is 0: the basket is not paused
+pub fn migrate_basket_v5(ctx: Context<MigrateBasketV5Context>) -> Result<()> {
+    let basket_config = &ctx.accounts.basket_config;
+
+    let old = {
+        let raw_data = basket_config.try_borrow_data()?;
+        require!(
+            raw_data[..8] == BasketConfig::DISCRIMINATOR[..],
+            PieError::InvalidBasket
+        );
+        // skip 8 byte discriminator
+        BasketConfigV2::deserialize(&mut &raw_data[8..])?
+    };
+    require!(old.version == 2, PieError::InvalidBasket);
+    require!(
+        ctx.remaining_accounts.len() == old.components.len(),
+        PieError::InvalidRemainingAccounts
+    );
+
+    let decimals = old
+        .components
+        .iter()
+        .zip(ctx.remaining_accounts.iter())
+        .map(|(component, mint)| Ok(read_component_mint(&component.mint, mint)?.0))
+        .collect::<Result<Vec<u8>>>()?;
+
+    let upgraded = upgrade_basket_config(old, &decimals);
+
+    realloc_basket_config(
+        basket_config,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let raw_data = &mut basket_config.try_borrow_mut_data()?;
+    upgraded.serialize(&mut &mut raw_data[8..])?;
+
+    Ok(())
+}
+
+/// Grows a basket config account to `BasketConfig::INIT_SPACE`, topping up rent from `payer`.
+fn realloc_basket_config<'info>(
+    basket_config: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required_space = basket_config.data_len().max(BasketConfig::INIT_SPACE);
+    let new_minimum_balance = Rent::get()?.minimum_balance(required_space);
+    if basket_config.lamports() < new_minimum_balance {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: basket_config.clone(),
+                },
+            ),
+            new_minimum_balance - basket_config.lamports(),
+        )?;
+    }
+    basket_config.realloc(required_space, false)?;
+
+    Ok(())
+}
+
+fn upgrade_basket_config(old: BasketConfigV2, decimals: &[u8]) -> BasketConfig {
+    BasketConfig {
+        bump: old.bump,
+        id: old.id,
+        version: 5,
+        mint: old.mint,
+        creator: old.creator,
+        rebalancer: old.rebalancer,
+        state: old.state,
+        rebalance_type: old.rebalance_type,
+        creator_fee_bp: old.creator_fee_bp,
+        components: old
+            .components
+            .into_iter()
+            .zip(decimals.iter())
+            .map(|(component, decimals)| BasketComponent {
+                mint: component.mint,
+                quantity_in_sys_decimal: component.quantity_in_sys_decimal,
+                decimals: *decimals,
+                target_weight_bp: 0,
+            })
+            .collect(),
+        management_fee_bp: 0,
+        last_management_fee_accrual_ts: 0,
+        performance_fee_bp: 0,
+        high_water_mark: 0,
+        weight_tolerance_bp: 0,
+        max_rebalance_slippage_bp: 0,
+        max_rebalance_loss_bp: 0,
+        rebalance_start_value: 0,
+        rebalance_loss_value: 0,
+        max_supply: 0,
+        max_holding_per_wallet: 0,
+        allowlist_mode: AllowlistMode::None,
+        allowlist_root: [0; 32],
+        permissionless_components: false,
+        pause_flags: 0,
+        reserved: [0; 86],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        instructions::BasketComponentV1,
+        states::{BasketState, RebalanceType},
+    };
+
+    #[test]
+    fn test_upgrade_basket_config() {
+        let old = BasketConfigV2 {
+            bump: 255,
+            id: 7,
+            version: 2,
+            mint: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            rebalancer: Pubkey::new_unique(),
+            state: BasketState::Active,
+            rebalance_type: RebalanceType::Dynamic,
+            creator_fee_bp: 50,
+            components: vec![
+                BasketComponentV1 {
+                    mint: Pubkey::new_unique(),
+                    quantity_in_sys_decimal: 1_000_000,
+                },
+                BasketComponentV1 {
+                    mint: Pubkey::new_unique(),
+                    quantity_in_sys_decimal: 500_000_000,
+                },
+            ],
+            reserved: [0; 10],
+        };
+        let mut raw_data = vec![0u8; BasketConfig::INIT_SPACE];
+        old.serialize(&mut &mut raw_data[8..]).unwrap();
+
+        let old = BasketConfigV2::deserialize(&mut &raw_data[8..]).unwrap();
+        let old_components = old.components.clone();
+        let upgraded = upgrade_basket_config(old, &[6, 9]);
+        upgraded.serialize(&mut &mut raw_data[8..]).unwrap();
+
+        let new = BasketConfig::deserialize(&mut &raw_data[8..]).unwrap();
+        assert_eq!(new.id, 7);
+        assert_eq!(new.version, 5);
+        assert_eq!(new.creator_fee_bp, 50);
+        assert_eq!(new.management_fee_bp, 0);
+        assert_eq!(new.last_management_fee_accrual_ts, 0);
+        assert_eq!(new.components.len(), 2);
+        assert_eq!(new.components[0].mint, old_components[0].mint);
+        assert_eq!(new.components[0].decimals, 6);
+        assert_eq!(new.components[1].quantity_in_sys_decimal, 500_000_000);
+        assert_eq!(new.components[1].decimals, 9);
+        assert!(!new.is_weighted());
+        assert_eq!(new.allowlist_mode, AllowlistMode::None);
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::{
+    prelude::{
+        borsh::{BorshDeserialize, BorshSerialize},
+        *,
+    },
+    Discriminator,
+};
+
+use crate::{constant::PROGRAM_STATE, error::PieError, ProgramState};
+
+#[derive(Accounts)]
+pub struct MigrateProgramStateContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: we are manually deserializing it
+    #[account(mut, seeds = [PROGRAM_STATE], bump, owner = crate::ID)]
+    pub program_state: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ProgramStateV1 {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub platform_fee_wallet: Pubkey,
+    pub basket_counter: u64,
+    pub basket_creation_fee: u64,
+    pub platform_fee_bp: u64,
+    pub is_initialized: bool,
+}
+
+impl ProgramStateV1 {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 1;
+}
+
+/// Grows the program state to the current layout, which adds the creator whitelist toggle.
+/// Additional rent is paid by the admin.
+pub fn migrate_program_state(ctx: Context<MigrateProgramStateContext>) -> Result<()> {
+    let program_state = &ctx.accounts.program_state;
+
+    let old = {
+        let raw_data = program_state.try_borrow_data()?;
+        require!(
+            raw_data.len() == ProgramStateV1::LEN
+                && raw_data[..8] == ProgramState::DISCRIMINATOR[..],
+            PieError::ProgramInitialized
+        );
+        // skip 8 byte discriminator
+        ProgramStateV1::deserialize(&mut &raw_data[8..])?
+    };
+    require!(
+        old.admin == ctx.accounts.admin.key(),
+        PieError::Unauthorized
+    );
+
+    let upgraded = ProgramState {
+        bump: old.bump,
+        admin: old.admin,
+        platform_fee_wallet: old.platform_fee_wallet,
+        basket_counter: old.basket_counter,
+        basket_creation_fee: old.basket_creation_fee,
+        platform_fee_bp: old.platform_fee_bp,
+        is_initialized: old.is_initialized,
+        creator_whitelist_enabled: false,
+        guardian: Pubkey::default(),
+        pause_flags: 0,
+        reserved: [0; 11],
+    };
+
+    let new_minimum_balance = Rent::get()?.minimum_balance(ProgramState::INIT_SPACE);
+    if program_state.lamports() < new_minimum_balance {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: program_state.clone(),
+                },
+            ),
+            new_minimum_balance - program_state.lamports(),
+        )?;
+    }
+    program_state.realloc(ProgramState::INIT_SPACE, false)?;
+
+    let raw_data = &mut program_state.try_borrow_mut_data()?;
+    upgraded.serialize(&mut &mut raw_data[8..])?;
+
+    Ok(())
+}
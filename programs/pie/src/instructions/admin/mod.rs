@@ -12,3 +12,45 @@ pub use update_platform_fee_wallet::*;
 
 pub mod migrate_basket;
 pub use migrate_basket::*;
+
+pub mod set_price_feed;
+pub use set_price_feed::*;
+
+pub mod set_twap_price_feed;
+pub use set_twap_price_feed::*;
+
+pub mod crystallize_performance_fee;
+pub use crystallize_performance_fee::*;
+
+pub mod migrate_basket_v5;
+pub use migrate_basket_v5::*;
+
+pub mod migrate_program_state;
+pub use migrate_program_state::*;
+
+pub mod update_creator_whitelist;
+pub use update_creator_whitelist::*;
+
+pub mod add_whitelisted_creator;
+pub use add_whitelisted_creator::*;
+
+pub mod remove_whitelisted_creator;
+pub use remove_whitelisted_creator::*;
+
+pub mod set_token_registry_entry;
+pub use set_token_registry_entry::*;
+
+pub mod update_guardian;
+pub use update_guardian::*;
+
+pub mod pause_program;
+pub use pause_program::*;
+
+pub mod unpause_program;
+pub use unpause_program::*;
+
+pub mod pause_basket;
+pub use pause_basket::*;
+
+pub mod unpause_basket;
+pub use unpause_basket::*;
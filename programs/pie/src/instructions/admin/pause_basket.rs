@@ -0,0 +1,54 @@
+use crate::{
+    constant::{BASKET_CONFIG, PAUSE_ALL, PROGRAM_STATE},
+    error::PieError,
+    BasketConfig, ProgramState,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PauseBasketContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.guardian == authority.key()
+            || program_state.admin == authority.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+}
+
+#[event]
+pub struct PauseBasketEvent {
+    pub basket_id: u64,
+    pub authority: Pubkey,
+    pub pause_flags: u64,
+}
+
+/// Pauses the `PAUSE_*` operations in `pause_flags` for one basket.
+/// Callable by the guardian or the admin.
+pub fn pause_basket(ctx: Context<PauseBasketContext>, pause_flags: u64) -> Result<()> {
+    require!(
+        pause_flags != 0 && pause_flags & !PAUSE_ALL == 0,
+        PieError::InvalidPauseFlags
+    );
+
+    let basket_config = &mut ctx.accounts.basket_config;
+    basket_config.pause_flags |= pause_flags;
+
+    emit!(PauseBasketEvent {
+        basket_id: basket_config.id,
+        authority: ctx.accounts.authority.key(),
+        pause_flags: basket_config.pause_flags,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::{
+    constant::{PAUSE_ALL, PROGRAM_STATE},
+    error::PieError,
+    ProgramState,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PauseProgramContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.guardian == authority.key()
+            || program_state.admin == authority.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct PauseProgramEvent {
+    pub authority: Pubkey,
+    pub pause_flags: u64,
+}
+
+/// Pauses the `PAUSE_*` operations in `pause_flags` for every basket.
+/// Callable by the guardian or the admin.
+pub fn pause_program(ctx: Context<PauseProgramContext>, pause_flags: u64) -> Result<()> {
+    require!(
+        pause_flags != 0 && pause_flags & !PAUSE_ALL == 0,
+        PieError::InvalidPauseFlags
+    );
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.pause_flags |= pause_flags;
+
+    emit!(PauseProgramEvent {
+        authority: ctx.accounts.authority.key(),
+        pause_flags: program_state.pause_flags,
+    });
+
+    Ok(())
+}
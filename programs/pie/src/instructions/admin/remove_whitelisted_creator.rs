@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{CREATOR_WHITELIST, PROGRAM_STATE},
+    error::PieError,
+    states::CreatorWhitelist,
+    ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct RemoveWhitelistedCreatorContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_WHITELIST],
+        bump = creator_whitelist.bump
+    )]
+    pub creator_whitelist: Box<Account<'info, CreatorWhitelist>>,
+}
+
+#[event]
+pub struct RemoveWhitelistedCreatorEvent {
+    pub creator: Pubkey,
+}
+
+/// Removes a creator from the whitelist. Baskets it already created are not affected.
+pub fn remove_whitelisted_creator(
+    ctx: Context<RemoveWhitelistedCreatorContext>,
+    creator: Pubkey,
+) -> Result<()> {
+    ctx.accounts.creator_whitelist.remove_creator(&creator)?;
+
+    emit!(RemoveWhitelistedCreatorEvent { creator });
+
+    Ok(())
+}
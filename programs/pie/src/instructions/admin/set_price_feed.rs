@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constant::{PRICE_FEED, PROGRAM_STATE},
+    error::PieError,
+    states::{PriceFeed, PriceSourceKind},
+    ProgramState, BASIS_POINTS,
+};
+
+#[derive(Accounts)]
+pub struct SetPriceFeedContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PriceFeed::INIT_SPACE,
+        seeds = [PRICE_FEED, mint.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Box<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SetPriceFeedEvent {
+    pub mint: Pubkey,
+    pub feed_id: [u8; 32],
+    pub max_staleness_secs: u64,
+    pub max_confidence_bp: u64,
+}
+
+/// Registers or updates the Pyth price feed used to price `mint`.
+/// Prices are rejected when older than `max_staleness_secs` or when their confidence
+/// interval exceeds `max_confidence_bp` of the price.
+pub fn set_price_feed(
+    ctx: Context<SetPriceFeedContext>,
+    feed_id: [u8; 32],
+    max_staleness_secs: u64,
+    max_confidence_bp: u64,
+) -> Result<()> {
+    require!(max_staleness_secs > 0, PieError::InvalidPriceFeed);
+    require!(
+        max_confidence_bp > 0 && max_confidence_bp <= BASIS_POINTS,
+        PieError::InvalidPriceFeed
+    );
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.bump = ctx.bumps.price_feed;
+    price_feed.mint = ctx.accounts.mint.key();
+    price_feed.feed_id = feed_id;
+    price_feed.max_staleness_secs = max_staleness_secs;
+    price_feed.max_confidence_bp = max_confidence_bp;
+    price_feed.source = PriceSourceKind::Pyth;
+    price_feed.pool = Pubkey::default();
+    price_feed.twap_window_secs = 0;
+
+    emit!(SetPriceFeedEvent {
+        mint: price_feed.mint,
+        feed_id,
+        max_staleness_secs,
+        max_confidence_bp,
+    });
+
+    Ok(())
+}
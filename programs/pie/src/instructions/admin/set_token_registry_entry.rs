@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constant::{PROGRAM_STATE, TOKEN_REGISTRY},
+    error::PieError,
+    states::{TokenRegistryEntry, TokenStatus},
+    ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct SetTokenRegistryEntryContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = TokenRegistryEntry::INIT_SPACE,
+        seeds = [TOKEN_REGISTRY, mint.key().as_ref()],
+        bump
+    )]
+    pub token_registry_entry: Box<Account<'info, TokenRegistryEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SetTokenRegistryEntryEvent {
+    pub mint: Pubkey,
+    pub status: TokenStatus,
+    pub risk_flags: u64,
+}
+
+/// Lists or updates `mint` in the token registry.
+/// Suspending a mint prevents baskets from adding it, it does not remove existing components.
+pub fn set_token_registry_entry(
+    ctx: Context<SetTokenRegistryEntryContext>,
+    status: TokenStatus,
+    risk_flags: u64,
+) -> Result<()> {
+    let token_registry_entry = &mut ctx.accounts.token_registry_entry;
+    token_registry_entry.bump = ctx.bumps.token_registry_entry;
+    token_registry_entry.mint = ctx.accounts.mint.key();
+    token_registry_entry.status = status;
+    token_registry_entry.risk_flags = risk_flags;
+
+    emit!(SetTokenRegistryEntryEvent {
+        mint: token_registry_entry.mint,
+        status,
+        risk_flags,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constant::{MIN_TWAP_WINDOW_SECS, PRICE_FEED, PROGRAM_STATE},
+    error::PieError,
+    states::{PriceFeed, PriceSourceKind},
+    ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct SetTwapPriceFeedContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Pool of the TWAP, owned by the program of `source`. Its tokens and observation
+    /// account are checked whenever the price is loaded.
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PriceFeed::INIT_SPACE,
+        seeds = [PRICE_FEED, mint.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Box<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SetTwapPriceFeedEvent {
+    pub mint: Pubkey,
+    pub source: PriceSourceKind,
+    pub pool: Pubkey,
+    pub twap_window_secs: u32,
+    pub max_staleness_secs: u64,
+}
+
+/// Registers or updates a pool TWAP as the price feed of `mint`.
+/// The average runs over at least `twap_window_secs` up to the newest pool observation, which
+/// is rejected when older than `max_staleness_secs`. The TWAP is converted to USD with the
+/// Pyth feed of the other token of the pool.
+pub fn set_twap_price_feed(
+    ctx: Context<SetTwapPriceFeedContext>,
+    source: PriceSourceKind,
+    twap_window_secs: u32,
+    max_staleness_secs: u64,
+) -> Result<()> {
+    require!(
+        twap_window_secs >= MIN_TWAP_WINDOW_SECS,
+        PieError::TwapWindowTooShort
+    );
+    require!(max_staleness_secs > 0, PieError::InvalidPriceFeed);
+
+    let pool_program = match source {
+        PriceSourceKind::RaydiumClmmTwap => raydium_clmm_cpi::ID,
+        PriceSourceKind::RaydiumCpmmTwap => raydium_cpmm_cpi::ID,
+        PriceSourceKind::Pyth => return err!(PieError::InvalidPriceFeed),
+    };
+    require!(
+        *ctx.accounts.pool.owner == pool_program,
+        PieError::InvalidPriceFeed
+    );
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.bump = ctx.bumps.price_feed;
+    price_feed.mint = ctx.accounts.mint.key();
+    price_feed.feed_id = [0; 32];
+    price_feed.max_staleness_secs = max_staleness_secs;
+    price_feed.max_confidence_bp = 0;
+    price_feed.source = source;
+    price_feed.pool = ctx.accounts.pool.key();
+    price_feed.twap_window_secs = twap_window_secs;
+
+    emit!(SetTwapPriceFeedEvent {
+        mint: price_feed.mint,
+        source,
+        pool: price_feed.pool,
+        twap_window_secs,
+        max_staleness_secs,
+    });
+
+    Ok(())
+}
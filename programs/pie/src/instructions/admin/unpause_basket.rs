@@ -0,0 +1,50 @@
+use crate::{
+    constant::{BASKET_CONFIG, PAUSE_ALL, PROGRAM_STATE},
+    error::PieError,
+    BasketConfig, ProgramState,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnpauseBasketContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+}
+
+#[event]
+pub struct UnpauseBasketEvent {
+    pub basket_id: u64,
+    pub pause_flags: u64,
+}
+
+/// Resumes the `PAUSE_*` operations in `pause_flags` for one basket. Admin only.
+pub fn unpause_basket(ctx: Context<UnpauseBasketContext>, pause_flags: u64) -> Result<()> {
+    require!(
+        pause_flags != 0 && pause_flags & !PAUSE_ALL == 0,
+        PieError::InvalidPauseFlags
+    );
+
+    let basket_config = &mut ctx.accounts.basket_config;
+    basket_config.pause_flags &= !pause_flags;
+
+    emit!(UnpauseBasketEvent {
+        basket_id: basket_config.id,
+        pause_flags: basket_config.pause_flags,
+    });
+
+    Ok(())
+}
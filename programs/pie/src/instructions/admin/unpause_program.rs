@@ -0,0 +1,42 @@
+use crate::{
+    constant::{PAUSE_ALL, PROGRAM_STATE},
+    error::PieError,
+    ProgramState,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnpauseProgramContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct UnpauseProgramEvent {
+    pub pause_flags: u64,
+}
+
+/// Resumes the `PAUSE_*` operations in `pause_flags`. Admin only.
+pub fn unpause_program(ctx: Context<UnpauseProgramContext>, pause_flags: u64) -> Result<()> {
+    require!(
+        pause_flags != 0 && pause_flags & !PAUSE_ALL == 0,
+        PieError::InvalidPauseFlags
+    );
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.pause_flags &= !pause_flags;
+
+    emit!(UnpauseProgramEvent {
+        pause_flags: program_state.pause_flags,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::PieError, ProgramState, PROGRAM_STATE};
+
+#[derive(Accounts)]
+pub struct UpdateCreatorWhitelistContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct UpdateCreatorWhitelistEvent {
+    pub creator_whitelist_enabled: bool,
+}
+
+/// Switches basket creation between whitelisted creators only and open to anyone.
+pub fn update_creator_whitelist(
+    ctx: Context<UpdateCreatorWhitelistContext>,
+    creator_whitelist_enabled: bool,
+) -> Result<()> {
+    ctx.accounts.program_state.creator_whitelist_enabled = creator_whitelist_enabled;
+
+    emit!(UpdateCreatorWhitelistEvent {
+        creator_whitelist_enabled,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::{constant::PROGRAM_STATE, error::PieError, ProgramState};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateGuardianContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump,
+        constraint = program_state.admin == admin.key() @ PieError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+#[event]
+pub struct UpdateGuardianEvent {
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
+
+pub fn update_guardian(ctx: Context<UpdateGuardianContext>, new_guardian: Pubkey) -> Result<()> {
+    let old_guardian = ctx.accounts.program_state.guardian;
+    ctx.accounts.program_state.guardian = new_guardian;
+
+    emit!(UpdateGuardianEvent {
+        old_guardian,
+        new_guardian,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, MintTo, Token, TokenAccount},
+    token_interface::Mint,
+};
+
+use crate::{
+    constant::{BASKET_CONFIG, PROGRAM_STATE},
+    error::PieError,
+    states::BasketState,
+    utils::{calculate_management_fee_amount, split_fee_amount},
+    BasketConfig, ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct AccrueManagementFeeContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Platform fee wallet that receives the fee
+    #[account(address = program_state.platform_fee_wallet)]
+    pub platform_fee_wallet: AccountInfo<'info>,
+
+    /// CHECK: Creator wallet that receives the fee
+    #[account(address = basket_config.creator)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = basket_mint,
+        associated_token::authority = platform_fee_wallet,
+    )]
+    pub platform_fee_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = basket_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AccrueManagementFeeEvent {
+    pub basket_id: u64,
+    pub basket_mint: Pubkey,
+    pub elapsed: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub timestamp: i64,
+}
+
+/// Accrues the basket's annualized management fee since the last accrual and mints it
+/// as basket tokens to the platform and the creator. Anyone can call this instruction.
+/// Component quantities are diluted so that the vault keeps backing the new supply.
+pub fn accrue_management_fee(ctx: Context<AccrueManagementFeeContext>) -> Result<()> {
+    let basket_config = &mut ctx.accounts.basket_config;
+    require!(
+        basket_config.state == BasketState::Active,
+        PieError::OnlyDefaultState
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let last_accrual_ts = basket_config.last_management_fee_accrual_ts;
+
+    // Baskets created before management fees existed start accruing from now
+    if last_accrual_ts == 0 {
+        basket_config.last_management_fee_accrual_ts = now;
+        return Ok(());
+    }
+
+    let elapsed: u64 = now
+        .checked_sub(last_accrual_ts)
+        .ok_or(PieError::InvalidAmount)?
+        .try_into()
+        .map_err(|_| PieError::ConversionFailure)?;
+    let total_supply = ctx.accounts.basket_mint.supply;
+
+    let fee_amount = calculate_management_fee_amount(
+        total_supply,
+        basket_config.management_fee_bp,
+        elapsed,
+    )?;
+
+    // Keep accruing from the previous timestamp if the fee is still rounded down to zero,
+    // otherwise frequent calls could skip the fee entirely
+    if fee_amount == 0 && total_supply > 0 && basket_config.management_fee_bp > 0 {
+        return Ok(());
+    }
+
+    basket_config.last_management_fee_accrual_ts = now;
+
+    let (platform_fee_amount, creator_fee_amount) = split_fee_amount(
+        fee_amount,
+        ctx.accounts.program_state.platform_fee_bp,
+        basket_config.creator_fee_bp,
+    )?;
+
+    let signer: &[&[&[u8]]] = &[&[
+        BASKET_CONFIG,
+        &basket_config.id.to_be_bytes(),
+        &[basket_config.bump],
+    ]];
+
+    if platform_fee_amount > 0 {
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.basket_mint.to_account_info(),
+                    to: ctx.accounts.platform_fee_basket_token_account.to_account_info(),
+                    authority: basket_config.to_account_info(),
+                },
+                signer,
+            ),
+            platform_fee_amount,
+        )?;
+    }
+
+    if creator_fee_amount > 0 {
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.basket_mint.to_account_info(),
+                    to: ctx.accounts.creator_basket_token_account.to_account_info(),
+                    authority: basket_config.to_account_info(),
+                },
+                signer,
+            ),
+            creator_fee_amount,
+        )?;
+    }
+
+    basket_config.dilute_components(
+        total_supply,
+        total_supply.checked_add(fee_amount).unwrap(),
+    )?;
+
+    emit!(AccrueManagementFeeEvent {
+        basket_id: basket_config.id,
+        basket_mint: basket_config.mint,
+        elapsed,
+        platform_fee: platform_fee_amount,
+        creator_fee: creator_fee_amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
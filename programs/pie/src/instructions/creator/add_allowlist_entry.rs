@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{AllowlistEntry, BasketConfig};
+use crate::{error::PieError, ALLOWLIST_ENTRY, BASKET_CONFIG};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AddAllowlistEntryContext<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.creator == creator.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = AllowlistEntry::INIT_SPACE,
+        seeds = [ALLOWLIST_ENTRY, &basket_config.id.to_be_bytes(), user.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AddAllowlistEntryEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+}
+
+pub fn add_allowlist_entry(ctx: Context<AddAllowlistEntryContext>, user: Pubkey) -> Result<()> {
+    let allowlist_entry = &mut ctx.accounts.allowlist_entry;
+    allowlist_entry.bump = ctx.bumps.allowlist_entry;
+    allowlist_entry.basket_id = ctx.accounts.basket_config.id;
+    allowlist_entry.user = user;
+    allowlist_entry.payer = ctx.accounts.creator.key();
+
+    emit!(AddAllowlistEntryEvent {
+        basket_id: allowlist_entry.basket_id,
+        user,
+    });
+    Ok(())
+}
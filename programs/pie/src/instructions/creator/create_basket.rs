@@ -6,13 +6,14 @@ use anchor_spl::token::{Mint, Token};
 use std::collections::HashSet;
 
 use crate::states::BasketState;
+use crate::utils::{check_token_approved, read_component_mint, Calculator};
 use crate::{
     constant::{BASKET_CONFIG, PROGRAM_STATE},
     error::PieError,
-    states::RebalanceType,
+    states::{AllowlistMode, CreatorWhitelist, RebalanceType},
     BasketComponent, BasketConfig, ProgramState,
 };
-use crate::{BASKET_DECIMALS, BASKET_MINT};
+use crate::{BASKET_DECIMALS, BASKET_MINT, MAX_MANAGEMENT_FEE_BP, MAX_PERFORMANCE_FEE_BP};
 
 #[derive(Accounts)]
 #[instruction(args: CreateBasketArgs)]
@@ -61,17 +62,70 @@ pub struct CreateBasketContext<'info> {
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// Required when `program_state.creator_whitelist_enabled` is set
+    pub creator_whitelist: Option<Account<'info, CreatorWhitelist>>,
+    // remaining accounts:
+    // the mint of each component, in `args.components` order
+    // unless `args.permissionless_components`, the token registry entry of each component,
+    // in `args.components` order
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ComponentQuantity {
+    /// Raw component units per basket token base unit, scaled by `SYS_DECIMALS`
+    SysDecimal(u128),
+    /// `amount / 10^scale` whole component tokens per whole basket token,
+    /// e.g. `{ amount: 5, scale: 1 }` is 0.5 token
+    Ui { amount: u64, scale: u8 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateBasketComponentArgs {
+    pub mint: Pubkey,
+    pub quantity: ComponentQuantity,
+    /// Target value weight, 0 for every component of a quantity based basket
+    pub target_weight_bp: u64,
+}
+
+impl CreateBasketComponentArgs {
+    /// Builds the basket component, reading the decimals from the component `mint` account.
+    pub fn to_basket_component(&self, mint: &AccountInfo) -> Result<BasketComponent> {
+        let (decimals, _) = read_component_mint(&self.mint, mint)?;
+
+        let quantity_in_sys_decimal = match self.quantity {
+            ComponentQuantity::SysDecimal(quantity) => quantity,
+            ComponentQuantity::Ui { amount, scale } => {
+                Calculator::ui_to_quantity_in_sys_decimal(amount, scale, decimals)?
+            }
+        };
+
+        Ok(BasketComponent {
+            mint: self.mint,
+            quantity_in_sys_decimal,
+            decimals,
+            target_weight_bp: self.target_weight_bp,
+        })
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateBasketArgs {
-    pub components: Vec<BasketComponent>,
+    pub components: Vec<CreateBasketComponentArgs>,
     pub name: String,
     pub symbol: String,
     pub uri: String,
     pub rebalancer: Pubkey,
     pub rebalance_type: RebalanceType,
     pub creator_fee_bp: u64,
+    pub management_fee_bp: u64,
+    pub performance_fee_bp: u64,
+    pub weight_tolerance_bp: u64,
+    pub max_supply: u64,
+    pub max_holding_per_wallet: u64,
+    pub allowlist_mode: AllowlistMode,
+    pub allowlist_root: [u8; 32],
+    pub permissionless_components: bool,
 }
 
 #[event]
@@ -85,25 +139,82 @@ pub struct CreateBasketEvent {
     pub components: Vec<BasketComponent>,
     pub rebalance_type: RebalanceType,
     pub creator_fee_bp: u64,
+    pub management_fee_bp: u64,
+    pub performance_fee_bp: u64,
+    pub weight_tolerance_bp: u64,
+    pub max_supply: u64,
+    pub max_holding_per_wallet: u64,
+    pub allowlist_mode: AllowlistMode,
+    pub allowlist_root: [u8; 32],
+    pub permissionless_components: bool,
 }
 
 pub fn create_basket(ctx: Context<CreateBasketContext>, args: CreateBasketArgs) -> Result<()> {
+    // Token registry entries follow the mints unless the basket opts out of the registry
+    let entries_len = if args.permissionless_components {
+        0
+    } else {
+        args.components.len()
+    };
+    require!(
+        ctx.remaining_accounts.len() == args.components.len() + entries_len,
+        PieError::InvalidRemainingAccounts
+    );
+    let (mint_accounts, token_registry_entries) =
+        ctx.remaining_accounts.split_at(args.components.len());
+    if ctx.accounts.program_state.creator_whitelist_enabled {
+        require!(
+            ctx.accounts
+                .creator_whitelist
+                .as_ref()
+                .is_some_and(|whitelist| whitelist.contains(&ctx.accounts.creator.key())),
+            PieError::CreatorNotWhitelisted
+        );
+    }
+    let components = args
+        .components
+        .iter()
+        .zip(mint_accounts.iter())
+        .map(|(component, mint)| component.to_basket_component(mint))
+        .collect::<Result<Vec<_>>>()?;
     // Validate components
-    validate_components(&args.components)?;
+    validate_components(&components, token_registry_entries)?;
+    require!(
+        args.management_fee_bp <= MAX_MANAGEMENT_FEE_BP,
+        PieError::InvalidFee
+    );
+    require!(
+        args.performance_fee_bp <= MAX_PERFORMANCE_FEE_BP,
+        PieError::InvalidFee
+    );
 
     let basket_config = &mut ctx.accounts.basket_config;
     let program_state = &mut ctx.accounts.program_state;
 
     basket_config.bump = ctx.bumps.basket_config;
     basket_config.id = program_state.basket_counter;
-    basket_config.version = 2;
+    basket_config.version = 5;
     basket_config.mint = ctx.accounts.basket_mint.key();
     basket_config.creator = ctx.accounts.creator.key();
     basket_config.rebalancer = args.rebalancer;
     basket_config.state = BasketState::Active;
     basket_config.rebalance_type = args.rebalance_type;
     basket_config.creator_fee_bp = args.creator_fee_bp;
-    basket_config.components = args.components.clone();
+    basket_config.components = components;
+    let target_weights_bp: Vec<u64> = args
+        .components
+        .iter()
+        .map(|component| component.target_weight_bp)
+        .collect();
+    basket_config.set_target_weights(&target_weights_bp, args.weight_tolerance_bp)?;
+    basket_config.management_fee_bp = args.management_fee_bp;
+    basket_config.last_management_fee_accrual_ts = Clock::get()?.unix_timestamp;
+    basket_config.performance_fee_bp = args.performance_fee_bp;
+    basket_config.high_water_mark = 0;
+    basket_config.max_supply = args.max_supply;
+    basket_config.max_holding_per_wallet = args.max_holding_per_wallet;
+    basket_config.set_allowlist(args.allowlist_mode, args.allowlist_root)?;
+    basket_config.permissionless_components = args.permissionless_components;
     program_state.basket_counter += 1;
 
     let signer: &[&[&[u8]]] = &[&[
@@ -170,14 +281,27 @@ pub fn create_basket(ctx: Context<CreateBasketContext>, args: CreateBasketArgs)
         components: basket_config.components.clone(),
         rebalance_type: basket_config.rebalance_type,
         creator_fee_bp: basket_config.creator_fee_bp,
+        management_fee_bp: basket_config.management_fee_bp,
+        performance_fee_bp: basket_config.performance_fee_bp,
+        weight_tolerance_bp: basket_config.weight_tolerance_bp,
+        max_supply: basket_config.max_supply,
+        max_holding_per_wallet: basket_config.max_holding_per_wallet,
+        allowlist_mode: basket_config.allowlist_mode,
+        allowlist_root: basket_config.allowlist_root,
+        permissionless_components: basket_config.permissionless_components,
     });
 
     Ok(())
 }
 
-fn validate_components(components: &[BasketComponent]) -> Result<()> {
+/// `token_registry_entries` holds the registry entry of each component, in `components`
+/// order, or is empty for baskets with `permissionless_components`.
+fn validate_components(
+    components: &[BasketComponent],
+    token_registry_entries: &[AccountInfo],
+) -> Result<()> {
     let mut mint_set = HashSet::new();
-    for component in components {
+    for (index, component) in components.iter().enumerate() {
         // Check for duplicates
         if !mint_set.insert(component.mint) {
             return Err(PieError::DuplicateComponent.into());
@@ -189,6 +313,10 @@ fn validate_components(components: &[BasketComponent]) -> Result<()> {
             component.quantity_in_sys_decimal > 0,
             PieError::InvalidComponentQuantity
         );
+
+        if let Some(entry) = token_registry_entries.get(index) {
+            check_token_approved(&component.mint, entry)?;
+        }
     }
 
     Ok(())
@@ -9,3 +9,24 @@ pub use transfer_basket::*;
 
 pub mod inactivate_basket;
 pub use inactivate_basket::*;
+
+pub mod accrue_management_fee;
+pub use accrue_management_fee::*;
+
+pub mod update_target_weights;
+pub use update_target_weights::*;
+
+pub mod update_rebalance_limits;
+pub use update_rebalance_limits::*;
+
+pub mod update_mint_limits;
+pub use update_mint_limits::*;
+
+pub mod update_allowlist;
+pub use update_allowlist::*;
+
+pub mod add_allowlist_entry;
+pub use add_allowlist_entry::*;
+
+pub mod remove_allowlist_entry;
+pub use remove_allowlist_entry::*;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{AllowlistEntry, BasketConfig};
+use crate::{error::PieError, ALLOWLIST_ENTRY, BASKET_CONFIG};
+
+#[derive(Accounts)]
+pub struct RemoveAllowlistEntryContext<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.creator == creator.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [ALLOWLIST_ENTRY, &basket_config.id.to_be_bytes(), allowlist_entry.user.as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: Account that paid the rent of the entry
+    #[account(mut, address = allowlist_entry.payer)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct RemoveAllowlistEntryEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+}
+
+/// Revokes an allowlist entry. Rent goes back to whoever paid it, the creator or the
+/// user who claimed the entry.
+pub fn remove_allowlist_entry(ctx: Context<RemoveAllowlistEntryContext>) -> Result<()> {
+    emit!(RemoveAllowlistEntryEvent {
+        basket_id: ctx.accounts.basket_config.id,
+        user: ctx.accounts.allowlist_entry.user,
+    });
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{AllowlistMode, BasketConfig};
+use crate::{error::PieError, BASKET_CONFIG};
+
+#[derive(Accounts)]
+pub struct UpdateAllowlistContext<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.creator == creator.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+}
+
+#[event]
+pub struct UpdateAllowlistEvent {
+    pub basket_id: u64,
+    pub allowlist_mode: AllowlistMode,
+    pub allowlist_root: [u8; 32],
+}
+
+/// Restricts deposits and mints to allowlisted users.
+/// Entries already claimed against a previous Merkle root stay valid until removed.
+pub fn update_allowlist(
+    ctx: Context<UpdateAllowlistContext>,
+    allowlist_mode: AllowlistMode,
+    allowlist_root: [u8; 32],
+) -> Result<()> {
+    let basket_config = &mut ctx.accounts.basket_config;
+    basket_config.set_allowlist(allowlist_mode, allowlist_root)?;
+
+    emit!(UpdateAllowlistEvent {
+        basket_id: basket_config.id,
+        allowlist_mode,
+        allowlist_root,
+    });
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::states::BasketConfig;
+use crate::{error::PieError, BASKET_CONFIG};
+
+#[derive(Accounts)]
+pub struct UpdateMintLimitsContext<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.creator == creator.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+}
+
+#[event]
+pub struct UpdateMintLimitsEvent {
+    pub basket_id: u64,
+    pub max_supply: u64,
+    pub max_holding_per_wallet: u64,
+}
+
+/// Caps the basket token supply and the amount a user can have minted net of their
+/// redemptions, as tracked in their `UserMintRecord`. A value of 0 removes the cap. Lowering
+/// a cap below the current supply or net minted amounts only blocks further mints.
+pub fn update_mint_limits(
+    ctx: Context<UpdateMintLimitsContext>,
+    max_supply: u64,
+    max_holding_per_wallet: u64,
+) -> Result<()> {
+    let basket_config = &mut ctx.accounts.basket_config;
+    basket_config.max_supply = max_supply;
+    basket_config.max_holding_per_wallet = max_holding_per_wallet;
+
+    emit!(UpdateMintLimitsEvent {
+        basket_id: basket_config.id,
+        max_supply,
+        max_holding_per_wallet,
+    });
+    Ok(())
+}
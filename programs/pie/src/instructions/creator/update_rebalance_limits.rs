@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{BasketConfig, BasketState};
+use crate::{error::PieError, BASIS_POINTS, BASKET_CONFIG};
+
+#[derive(Accounts)]
+pub struct UpdateRebalanceLimitsContext<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.creator == creator.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+}
+
+#[event]
+pub struct UpdateRebalanceLimitsEvent {
+    pub basket_id: u64,
+    pub max_rebalance_slippage_bp: u64,
+    pub max_rebalance_loss_bp: u64,
+}
+
+/// Bounds every rebalancing swap to `max_rebalance_slippage_bp` of the oracle value of its
+/// input, and every rebalancing session to `max_rebalance_loss_bp` of the basket value.
+/// Both limits are set together, setting both to 0 disables them.
+pub fn update_rebalance_limits(
+    ctx: Context<UpdateRebalanceLimitsContext>,
+    max_rebalance_slippage_bp: u64,
+    max_rebalance_loss_bp: u64,
+) -> Result<()> {
+    let basket_config = &mut ctx.accounts.basket_config;
+    require!(
+        basket_config.state != BasketState::Rebalancing,
+        PieError::RebalancingInProgress
+    );
+    require!(
+        max_rebalance_slippage_bp <= BASIS_POINTS && max_rebalance_loss_bp <= BASIS_POINTS,
+        PieError::InvalidAmount
+    );
+    require!(
+        (max_rebalance_slippage_bp == 0) == (max_rebalance_loss_bp == 0),
+        PieError::InvalidAmount
+    );
+
+    basket_config.max_rebalance_slippage_bp = max_rebalance_slippage_bp;
+    basket_config.max_rebalance_loss_bp = max_rebalance_loss_bp;
+
+    emit!(UpdateRebalanceLimitsEvent {
+        basket_id: basket_config.id,
+        max_rebalance_slippage_bp,
+        max_rebalance_loss_bp,
+    });
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{BasketConfig, BasketState};
+use crate::{error::PieError, BASKET_CONFIG};
+
+#[derive(Accounts)]
+pub struct UpdateTargetWeightsContext<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.creator == creator.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+}
+
+#[event]
+pub struct UpdateTargetWeightsEvent {
+    pub basket_id: u64,
+    pub target_weights_bp: Vec<u64>,
+    pub weight_tolerance_bp: u64,
+}
+
+/// Sets the target weight of each component, in `basket_config.components` order.
+/// Setting every weight to 0 turns the basket back into a quantity based basket.
+pub fn update_target_weights(
+    ctx: Context<UpdateTargetWeightsContext>,
+    target_weights_bp: Vec<u64>,
+    weight_tolerance_bp: u64,
+) -> Result<()> {
+    let basket_config = &mut ctx.accounts.basket_config;
+    require!(
+        basket_config.state != BasketState::Rebalancing,
+        PieError::RebalancingInProgress
+    );
+
+    basket_config.set_target_weights(&target_weights_bp, weight_tolerance_bp)?;
+
+    emit!(UpdateTargetWeightsEvent {
+        basket_id: basket_config.id,
+        target_weights_bp,
+        weight_tolerance_bp,
+    });
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token::Token, token_interface::TokenAccount};
 
 use crate::{
-    constant::USER_FUND,
+    constant::{PAUSE_MINT, USER_FUND},
     error::PieError,
     states::BasketState,
     utils::{calculate_fee_amount, transfer_fees, transfer_from_user_to_pool_vault},
@@ -89,6 +89,9 @@ pub fn deposit_component(ctx: Context<DepositComponent>, amount: u64) -> Result<
         ctx.accounts.basket_config.state == BasketState::Active,
         PieError::OnlyDefaultState
     );
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_MINT)?;
     let user_fund = &mut ctx.accounts.user_fund;
 
     // TODO: how should collect fees?
@@ -1,32 +1,5 @@
-pub mod buy_component;
-pub use buy_component::*;
-
-pub mod buy_component_cpmm;
-pub use buy_component_cpmm::*;
-
-pub mod buy_component_clmm;
-pub use buy_component_clmm::*;
-
 pub mod deposit_component;
 pub use deposit_component::*;
 
-pub mod sell_component;
-pub use sell_component::*;
-
-pub mod sell_component_cpmm;
-pub use sell_component_cpmm::*;
-
-pub mod sell_component_clmm;
-pub use sell_component_clmm::*;
-
 pub mod withdraw_component;
 pub use withdraw_component::*;
-
-pub mod execute_rebalancing;
-pub use execute_rebalancing::*;
-
-pub mod execute_rebalancing_cpmm;
-pub use execute_rebalancing_cpmm::*;
-
-pub mod execute_rebalancing_clmm;
-pub use execute_rebalancing_clmm::*;
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token::Token, token_interface::TokenAccount};
 
 use crate::{
-    constant::USER_FUND,
+    constant::{PAUSE_REDEEM, USER_FUND},
     error::PieError,
     utils::{calculate_fee_amount, transfer_fees, transfer_from_pool_vault_to_user},
     BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE,
@@ -74,6 +74,10 @@ pub struct WithdrawComponentEvent {
 }
 
 pub fn withdraw_component(ctx: Context<WithdrawComponent>, amount: u64) -> Result<()> {
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_REDEEM)?;
+
     let user_fund = &mut ctx.accounts.user_fund;
 
     let component = user_fund
@@ -0,0 +1,282 @@
+use crate::constant::{PAUSE_REBALANCE, PAUSE_SWAP, PROGRAM_STATE};
+use crate::states::{BasketState, RebalanceType, TokenRegistryEntry};
+use crate::utils::{
+    execute_swap, load_price, split_price_accounts, to_stored_value, value_in_quote, Rebalance,
+    SwapBounds, SwapRequest,
+};
+use crate::{error::PieError, BasketConfig, ProgramState, BASKET_CONFIG};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ExecuteRebalancing<'info> {
+    #[account(mut)]
+    pub rebalancer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump,
+        constraint = basket_config.rebalancer == rebalancer.key() @ PieError::Unauthorized
+    )]
+    pub basket_config: Account<'info, BasketConfig>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        address = vault_token_source.mint
+    )]
+    pub vault_token_source_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::authority = basket_config,
+        associated_token::mint = vault_token_source_mint,
+        associated_token::token_program = input_token_program
+    )]
+    pub vault_token_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = vault_token_destination.mint
+    )]
+    pub vault_token_destination_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = rebalancer,
+        associated_token::authority = basket_config,
+        associated_token::mint = vault_token_destination_mint,
+        associated_token::token_program = output_token_program
+    )]
+    pub vault_token_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program for input token transfers
+    pub input_token_program: Interface<'info, TokenInterface>,
+
+    /// SPL program for output token transfers
+    pub output_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Swap program will be checked against the venue of the swap request
+    pub swap_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Token registry entry of the destination mint, required to add it as a new component
+    pub destination_token_registry_entry: Option<Account<'info, TokenRegistryEntry>>,
+    // remaining accounts:
+    // for baskets with rebalance limits, price feed accounts of the source mint, then of
+    // the destination mint (see `price_accounts_len`)
+    // swap accounts of the venue
+}
+
+#[event]
+pub struct ExecuteRebalancingEvent {
+    pub basket_id: u64,
+    pub basket_mint: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub is_swap_base_out: bool,
+    pub initial_available_source_balance: u64,
+    pub initial_available_destination_balance: u64,
+    pub final_available_source_balance: u64,
+    pub final_available_destination_balance: u64,
+}
+
+/// Swaps a component of the basket through the venue of `request`
+pub fn execute_rebalancing<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ExecuteRebalancing<'info>>,
+    request: SwapRequest,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    rebalance_with_swap(
+        &mut accounts.basket_config,
+        &accounts.program_state,
+        &accounts.basket_mint,
+        &accounts.vault_token_source_mint,
+        &mut accounts.vault_token_source,
+        &accounts.vault_token_destination_mint,
+        &mut accounts.vault_token_destination,
+        accounts.destination_token_registry_entry.as_deref(),
+        &accounts.swap_program,
+        ctx.remaining_accounts,
+        &request,
+    )
+}
+
+/// Swaps from the source to the destination vault and updates the components of the basket
+/// with the available balances after the swap. Weighted baskets can neither add nor remove
+/// components.
+#[allow(clippy::too_many_arguments)]
+pub fn rebalance_with_swap<'info>(
+    basket_config: &mut Account<'info, BasketConfig>,
+    program_state: &ProgramState,
+    basket_mint: &InterfaceAccount<'info, Mint>,
+    vault_token_source_mint: &InterfaceAccount<'info, Mint>,
+    vault_token_source: &mut InterfaceAccount<'info, TokenAccount>,
+    vault_token_destination_mint: &InterfaceAccount<'info, Mint>,
+    vault_token_destination: &mut InterfaceAccount<'info, TokenAccount>,
+    destination_token_registry_entry: Option<&TokenRegistryEntry>,
+    swap_program: &AccountInfo<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    request: &SwapRequest,
+) -> Result<()> {
+    require!(
+        basket_config.state == BasketState::Rebalancing,
+        PieError::NotInRebalancing
+    );
+    basket_config.check_not_paused(program_state, PAUSE_REBALANCE | PAUSE_SWAP)?;
+
+    require!(
+        basket_config.rebalance_type != RebalanceType::Disabled,
+        PieError::RebalanceNotAllowedBasket
+    );
+
+    let basket_total_supply = basket_mint.supply;
+
+    let (source_price_accounts, destination_price_accounts, swap_accounts) =
+        if basket_config.has_rebalance_limits() {
+            let (source_price_accounts, rest) = split_price_accounts(remaining_accounts)?;
+            let (destination_price_accounts, rest) = split_price_accounts(rest)?;
+            (source_price_accounts, destination_price_accounts, rest)
+        } else {
+            (
+                &remaining_accounts[..0],
+                &remaining_accounts[..0],
+                remaining_accounts,
+            )
+        };
+    let (
+        initial_available_source_balance,
+        initial_available_destination_balance,
+        unminted_source_balance,
+        unminted_destination_balance,
+    ) = Rebalance::calculate_initial_balances(
+        basket_config,
+        vault_token_source,
+        vault_token_destination,
+        basket_total_supply,
+        0, // TODO: remove this field when removing raydium
+    )?;
+
+    execute_swap(
+        basket_config,
+        swap_program,
+        swap_accounts,
+        &vault_token_source.to_account_info(),
+        &vault_token_destination.to_account_info(),
+        request,
+        SwapBounds::default(),
+    )?;
+
+    let (final_available_source_balance, final_available_destination_balance) =
+        Rebalance::calculate_final_balances(
+            vault_token_source,
+            vault_token_destination,
+            unminted_source_balance,
+            unminted_destination_balance,
+        )?;
+
+    // Check swap result
+    require!(
+        initial_available_source_balance - final_available_source_balance > 0,
+        PieError::InvalidSwapResult
+    );
+
+    require!(
+        final_available_destination_balance - initial_available_destination_balance > 0,
+        PieError::InvalidSwapResult
+    );
+
+    // Bound the loss of the swap at oracle prices
+    if basket_config.has_rebalance_limits() {
+        let now = Clock::get()?.unix_timestamp;
+        let source_price = load_price(&vault_token_source.mint, source_price_accounts, now)?;
+        let destination_price = load_price(
+            &vault_token_destination.mint,
+            destination_price_accounts,
+            now,
+        )?;
+
+        let input_value = value_in_quote(
+            initial_available_source_balance - final_available_source_balance,
+            vault_token_source_mint.decimals,
+            &source_price,
+        )?;
+        let output_value = value_in_quote(
+            final_available_destination_balance - initial_available_destination_balance,
+            vault_token_destination_mint.decimals,
+            &destination_price,
+        )?;
+
+        basket_config.rebalance_loss_value = Rebalance::check_rebalance_loss(
+            to_stored_value(input_value, false)?,
+            to_stored_value(output_value, false)?,
+            basket_config.max_rebalance_slippage_bp,
+            basket_config.rebalance_start_value,
+            basket_config.rebalance_loss_value,
+            basket_config.max_rebalance_loss_bp,
+        )?;
+    }
+
+    // Weighted baskets keep the components their target weights cover until the weights are
+    // updated, which is not possible while rebalancing
+    if basket_config.is_weighted() {
+        require!(
+            basket_config
+                .components
+                .iter()
+                .any(|component| component.mint == vault_token_destination.mint),
+            PieError::ComponentChangeNotAllowedBasket
+        );
+        require!(
+            final_available_source_balance > 0
+                || basket_config.rebalance_type == RebalanceType::Fixed,
+            PieError::ComponentChangeNotAllowedBasket
+        );
+    }
+
+    // remove input component if final available balance is 0
+    if final_available_source_balance == 0 {
+        basket_config.remove_component(vault_token_source.mint);
+    } else {
+        basket_config.upsert_component(
+            vault_token_source.mint,
+            vault_token_source_mint.decimals,
+            final_available_source_balance,
+            basket_total_supply,
+            None,
+        )?;
+    }
+
+    basket_config.upsert_component(
+        vault_token_destination.mint,
+        vault_token_destination_mint.decimals,
+        final_available_destination_balance,
+        basket_total_supply,
+        destination_token_registry_entry,
+    )?;
+
+    emit!(ExecuteRebalancingEvent {
+        basket_id: basket_config.id,
+        basket_mint: basket_mint.key(),
+        input_mint: vault_token_source.mint,
+        output_mint: vault_token_destination.mint,
+        is_swap_base_out: request.is_swap_base_out(),
+        initial_available_source_balance,
+        initial_available_destination_balance,
+        final_available_source_balance,
+        final_available_destination_balance,
+    });
+
+    Ok(())
+}
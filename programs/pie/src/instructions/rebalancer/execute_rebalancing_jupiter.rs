@@ -1,12 +1,9 @@
-use crate::constant::JUPITER_PROGRAM_ID;
-use crate::instructions::ExecuteRebalancingEvent;
-use crate::states::{BasketState, RebalanceType};
-use crate::utils::Rebalance;
-use crate::{error::PieError, BasketConfig, BASKET_CONFIG};
-use anchor_lang::{
-    prelude::*,
-    solana_program::{instruction::Instruction, program::invoke_signed},
-};
+use crate::constant::PROGRAM_STATE;
+use crate::instructions::rebalance_with_swap;
+use crate::states::TokenRegistryEntry;
+use crate::utils::{JupiterSwap, SwapRequest};
+use crate::{error::PieError, BasketConfig, ProgramState, BASKET_CONFIG};
+use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::TokenAccount;
 use anchor_spl::token_interface::{Mint, TokenInterface};
@@ -22,6 +19,12 @@ pub struct ExecuteRebalancingJupiter<'info> {
         constraint = basket_config.rebalancer == rebalancer.key() @ PieError::Unauthorized
     )]
     pub basket_config: Account<'info, BasketConfig>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
     #[account(
         mut,
         address = basket_config.mint
@@ -65,127 +68,32 @@ pub struct ExecuteRebalancingJupiter<'info> {
     pub jupiter_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Token registry entry of the destination mint, required to add it as a new component
+    pub destination_token_registry_entry: Option<Account<'info, TokenRegistryEntry>>,
+    // remaining accounts:
+    // for baskets with rebalance limits, price feed and Pyth price update account of
+    // the source mint, then of the destination mint
+    // Jupiter accounts
 }
 
+/// Jupiter only variant of `execute_rebalancing`, kept for existing clients
 pub fn execute_rebalancing_jupiter<'a, 'b, 'c: 'info, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ExecuteRebalancingJupiter<'info>>,
     data: Vec<u8>,
 ) -> Result<()> {
-    require!(
-        ctx.accounts.basket_config.state == BasketState::Rebalancing,
-        PieError::NotInRebalancing
-    );
-
-    require!(
-        ctx.accounts.jupiter_program.key() == JUPITER_PROGRAM_ID,
-        PieError::InvalidJupiterProgram
-    );
-    require!(
-        ctx.accounts.basket_config.rebalance_type != RebalanceType::Disabled,
-        PieError::RebalanceNotAllowedBasket
-    );
-
-    let basket_total_supply = ctx.accounts.basket_mint.supply;
-
-    let (
-        initial_available_source_balance,
-        initial_available_destination_balance,
-        unminted_source_balance,
-        unminted_destination_balance,
-    ) = Rebalance::calculate_initial_balances(
-        &mut ctx.accounts.basket_config,
-        ctx.accounts.vault_token_source.as_ref(),
-        ctx.accounts.vault_token_destination.as_ref(),
-        basket_total_supply,
-        0, // TODO: remove this field when removing raydium
-    )?;
-
-    // Prepare accounts for jupiter program
-    let accounts: Vec<AccountMeta> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| {
-            let is_signer = acc.key == &ctx.accounts.basket_config.key();
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer,
-                is_writable: acc.is_writable,
-            }
-        })
-        .collect();
-
-    let accounts_infos: Vec<AccountInfo> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .collect();
-
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        BASKET_CONFIG,
-        &ctx.accounts.basket_config.id.to_be_bytes(),
-        &[ctx.accounts.basket_config.bump],
-    ]];
-
-    // Invoke jupiter program
-    invoke_signed(
-        &Instruction {
-            program_id: ctx.accounts.jupiter_program.key(),
-            accounts,
-            data,
-        },
-        &accounts_infos,
-        signer_seeds,
-    )?;
-
-    let (final_available_source_balance, final_available_destination_balance) =
-        Rebalance::calculate_final_balances(
-            &mut ctx.accounts.vault_token_source,
-            &mut ctx.accounts.vault_token_destination,
-            unminted_source_balance,
-            unminted_destination_balance,
-        )?;
-
-    // Check swap result
-    require!(
-        initial_available_source_balance - final_available_source_balance > 0,
-        PieError::InvalidSwapResult
-    );
-
-    require!(
-        final_available_destination_balance - initial_available_destination_balance > 0,
-        PieError::InvalidSwapResult
-    );
-
-    // remove input component if final available balance is 0
-    if final_available_source_balance == 0 {
-        ctx.accounts
-            .basket_config
-            .remove_component(ctx.accounts.vault_token_source.mint);
-    } else {
-        ctx.accounts.basket_config.upsert_component(
-            ctx.accounts.vault_token_source.mint,
-            final_available_source_balance,
-            basket_total_supply,
-        )?;
-    }
-
-    ctx.accounts.basket_config.upsert_component(
-        ctx.accounts.vault_token_destination.mint,
-        final_available_destination_balance,
-        basket_total_supply,
-    )?;
-
-    emit!(ExecuteRebalancingEvent {
-        basket_id: ctx.accounts.basket_config.id,
-        basket_mint: ctx.accounts.basket_mint.key(),
-        input_mint: ctx.accounts.vault_token_source.mint,
-        output_mint: ctx.accounts.vault_token_destination.mint,
-        is_swap_base_out: false, // TODO: remove this field when removing raydium
-        initial_available_source_balance,
-        initial_available_destination_balance,
-        final_available_source_balance,
-        final_available_destination_balance,
-    });
-
-    Ok(())
+    let accounts = ctx.accounts;
+    rebalance_with_swap(
+        &mut accounts.basket_config,
+        &accounts.program_state,
+        &accounts.basket_mint,
+        &accounts.vault_token_source_mint,
+        &mut accounts.vault_token_source,
+        &accounts.vault_token_destination_mint,
+        &mut accounts.vault_token_destination,
+        accounts.destination_token_registry_entry.as_deref(),
+        &accounts.jupiter_program,
+        ctx.remaining_accounts,
+        &SwapRequest::Jupiter(JupiterSwap { data }),
+    )
 }
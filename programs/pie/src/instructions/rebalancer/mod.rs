@@ -6,3 +6,6 @@ pub use stop_rebalancing::*;
 
 pub mod execute_rebalancing_jupiter;
 pub use execute_rebalancing_jupiter::*;
+
+pub mod execute_rebalancing;
+pub use execute_rebalancing::*;
@@ -1,6 +1,13 @@
 use anchor_lang::{prelude::*, solana_program::clock::Clock};
+use anchor_spl::token_interface::Mint;
 
-use crate::{error::PieError, states::BasketState, BasketConfig, BASKET_CONFIG};
+use crate::{
+    constant::{PAUSE_REBALANCE, PROGRAM_STATE},
+    error::PieError,
+    states::BasketState,
+    utils::{calculate_basket_nav, load_component_prices, to_stored_value},
+    BasketConfig, ProgramState, BASKET_CONFIG,
+};
 
 #[event]
 pub struct StartRebalancingEvent {
@@ -21,19 +28,50 @@ pub struct StartRebalancing<'info> {
         constraint = basket_config.rebalancer == rebalancer.key() @ PieError::Unauthorized
     )]
     pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(address = basket_config.mint)]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+    // remaining accounts, for each component of a basket with rebalance limits in `basket_config.components` order:
+    // price feed accounts of the component mint (see `price_accounts_len`)
 }
 
-pub fn start_rebalancing(ctx: Context<StartRebalancing>) -> Result<()> {
+pub fn start_rebalancing<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, StartRebalancing<'info>>,
+) -> Result<()> {
     let basket_config = &mut ctx.accounts.basket_config;
     require!(
         basket_config.state == BasketState::Active,
         PieError::OnlyDefaultState
     );
+    basket_config.check_not_paused(&ctx.accounts.program_state, PAUSE_REBALANCE)?;
 
     basket_config.state = BasketState::Rebalancing;
 
     let clock = Clock::get()?;
 
+    // Start a new loss budget from the current basket value
+    if basket_config.has_rebalance_limits() {
+        let prices = load_component_prices(
+            &basket_config.components,
+            ctx.remaining_accounts,
+            clock.unix_timestamp,
+        )?;
+        let nav = calculate_basket_nav(
+            &basket_config.components,
+            &prices,
+            ctx.accounts.basket_mint.supply,
+            None,
+        )?;
+        basket_config.rebalance_start_value = to_stored_value(nav.aum, false)?;
+        basket_config.rebalance_loss_value = 0;
+    }
+
     emit!(StartRebalancingEvent {
         basket_id: basket_config.id,
         mint: ctx.accounts.basket_config.mint,
@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
 
-use crate::{error::PieError, states::BasketState, BasketComponent, BasketConfig, BASKET_CONFIG};
+use crate::{
+    error::PieError,
+    states::BasketState,
+    utils::{load_component_prices, value_in_quote, Calculator, Rebalance},
+    BasketComponent, BasketConfig, BASKET_CONFIG,
+};
 
 #[event]
 pub struct StopRebalancingEvent {
@@ -22,22 +28,58 @@ pub struct StopRebalancing<'info> {
         constraint = basket_config.rebalancer == rebalancer.key() @ PieError::Unauthorized
     )]
     pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(address = basket_config.mint)]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+    // remaining accounts, for each component of a weighted basket in `basket_config.components` order:
+    // price feed accounts of the component mint (see `price_accounts_len`)
 }
 
-pub fn stop_rebalancing(ctx: Context<StopRebalancing>) -> Result<()> {
+pub fn stop_rebalancing<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, StopRebalancing<'info>>,
+) -> Result<()> {
     let basket_config = &mut ctx.accounts.basket_config;
     require!(
         basket_config.state == BasketState::Rebalancing,
         PieError::NotInRebalancing
     );
 
+    let now = Clock::get()?.unix_timestamp;
+    let total_supply = ctx.accounts.basket_mint.supply;
+
+    // Weighted baskets must end the rebalancing within tolerance of their target weights
+    if basket_config.is_weighted() && total_supply > 0 {
+        let prices = load_component_prices(&basket_config.components, ctx.remaining_accounts, now)?;
+        let mut values = Vec::with_capacity(basket_config.components.len());
+        for (component, price) in basket_config.components.iter().zip(prices.iter()) {
+            let amount = Calculator::restore_raw_decimal(
+                component
+                    .quantity_in_sys_decimal
+                    .checked_mul(total_supply.into())
+                    .unwrap(),
+            );
+            values.push(value_in_quote(amount, component.decimals, price)?);
+        }
+
+        let target_weights_bp: Vec<u64> = basket_config
+            .components
+            .iter()
+            .map(|component| component.target_weight_bp)
+            .collect();
+        Rebalance::check_target_weights(
+            &values,
+            &target_weights_bp,
+            basket_config.weight_tolerance_bp,
+        )?;
+    }
+
     basket_config.state = BasketState::Active;
 
     emit!(StopRebalancingEvent {
         basket_id: ctx.accounts.basket_config.id,
         mint: ctx.accounts.basket_config.mint,
         components: ctx.accounts.basket_config.components.clone(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
@@ -0,0 +1,148 @@
+use crate::{
+    constant::{PAUSE_MINT, PAUSE_SWAP, USER_FUND},
+    error::PieError,
+    states::{AllowlistEntry, BasketState},
+    utils::{execute_swap, SwapBounds, SwapOutcome, SwapRequest},
+    BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct BuyComponentContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_FUND, &user.key().as_ref(), &basket_config.id.to_be_bytes()],
+        bump
+    )]
+    pub user_fund: Box<Account<'info, UserFund>>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        associated_token::authority = basket_config,
+        associated_token::mint = NATIVE_MINT,
+    )]
+    pub vault_token_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::authority = basket_config,
+        associated_token::mint = vault_token_destination.mint,
+        associated_token::token_program = output_token_program
+    )]
+    pub vault_token_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program for output token transfers
+    pub output_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Swap program will be checked against the venue of the swap request
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// Allowlist entry of the user, required when the basket has an allowlist
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+    // remaining accounts: swap accounts of the venue
+}
+
+#[event]
+pub struct BuyComponentEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount_swapped: u64,
+    pub amount_received: u64,
+}
+
+/// Buys a component with the user's WSOL through the venue of `request`
+/// Before calling buy component, user must deposit WSOl with `deposit_wsol` instruction
+pub fn buy_component<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, BuyComponentContext<'info>>,
+    request: SwapRequest,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let outcome = buy_component_with_swap(
+        &accounts.user.key(),
+        &mut accounts.user_fund,
+        &accounts.basket_config,
+        &accounts.program_state,
+        accounts.allowlist_entry.as_deref(),
+        &accounts.vault_token_source,
+        &accounts.vault_token_destination,
+        &accounts.swap_program,
+        ctx.remaining_accounts,
+        &request,
+    )?;
+
+    emit!(BuyComponentEvent {
+        basket_id: accounts.basket_config.id,
+        user: accounts.user.key(),
+        mint: accounts.vault_token_destination.mint,
+        amount_swapped: outcome.amount_in,
+        amount_received: outcome.amount_out,
+    });
+
+    Ok(())
+}
+
+/// Swaps WSOL of the user fund into the component held by `vault_token_destination`
+/// and credits the component to the user fund.
+#[allow(clippy::too_many_arguments)]
+pub fn buy_component_with_swap<'info>(
+    user: &Pubkey,
+    user_fund: &mut UserFund,
+    basket_config: &Account<'info, BasketConfig>,
+    program_state: &ProgramState,
+    allowlist_entry: Option<&AllowlistEntry>,
+    vault_token_source: &InterfaceAccount<'info, TokenAccount>,
+    vault_token_destination: &InterfaceAccount<'info, TokenAccount>,
+    swap_program: &AccountInfo<'info>,
+    swap_accounts: &[AccountInfo<'info>],
+    request: &SwapRequest,
+) -> Result<SwapOutcome> {
+    require!(
+        basket_config.state == BasketState::Active,
+        PieError::OnlyDefaultState
+    );
+    basket_config.check_not_paused(program_state, PAUSE_MINT | PAUSE_SWAP)?;
+    basket_config.check_allowlisted(user, allowlist_entry)?;
+    require!(
+        basket_config
+            .components
+            .iter()
+            .any(|c| c.mint == vault_token_destination.mint),
+        PieError::InvalidComponent
+    );
+    let outcome = execute_swap(
+        basket_config,
+        swap_program,
+        swap_accounts,
+        &vault_token_source.to_account_info(),
+        &vault_token_destination.to_account_info(),
+        request,
+        SwapBounds::default(),
+    )?;
+
+    // Remove input token from user fund
+    // It will throw error if amount swapped is greater than the balance of the user fund
+    user_fund.remove_component(NATIVE_MINT, outcome.amount_in)?;
+
+    // Add output token to user fund
+    user_fund.upsert_component(vault_token_destination.mint, outcome.amount_out)?;
+
+    Ok(outcome)
+}
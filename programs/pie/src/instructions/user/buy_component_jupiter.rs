@@ -1,10 +1,7 @@
 use crate::{
-    constant::{JUPITER_PROGRAM_ID, USER_FUND}, error::PieError, states::BasketState, utils::calculate_amounts_swapped_and_received, BasketConfig, UserFund, BASKET_CONFIG, NATIVE_MINT
-};
-use anchor_lang::{
-    prelude::*,
-    solana_program::{instruction::Instruction, program::invoke_signed},
+    constant::USER_FUND, instructions::buy_component_with_swap, states::AllowlistEntry, utils::{JupiterSwap, SwapRequest}, BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE
 };
+use anchor_lang::prelude::*;
 use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface}
 };
@@ -28,6 +25,12 @@ pub struct BuyComponentJupiterContext<'info> {
     )]
     pub basket_config: Box<Account<'info, BasketConfig>>,
 
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
     #[account(
         mut,
         associated_token::authority = basket_config,
@@ -48,6 +51,9 @@ pub struct BuyComponentJupiterContext<'info> {
 
     /// CHECK: Jupiter program will be checked in require
     pub jupiter_program: UncheckedAccount<'info>,
+
+    /// Allowlist entry of the user, required when the basket has an allowlist
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
 }
 
 #[event]
@@ -59,100 +65,32 @@ pub struct BuyComponentJupiterEvent {
     pub amount_received: u64,
 }
 
-/// Buys a component using Jupiter with the user's WSOL 
+/// Buys a component using Jupiter with the user's WSOL, see `buy_component`
 /// Before calling buy component, user must deposit WSOl with `deposit_wsol` instruction
-pub fn buy_component_jupiter(
-    ctx: Context<BuyComponentJupiterContext>,
+pub fn buy_component_jupiter<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, BuyComponentJupiterContext<'info>>,
     data: Vec<u8>,
 ) -> Result<()> {
-    require!(
-        ctx.accounts.basket_config.state == BasketState::Active,
-        PieError::OnlyDefaultState
-    );
-    require!(
-        ctx.accounts
-            .basket_config
-            .components
-            .iter()
-            .any(|c| c.mint == ctx.accounts.vault_token_destination.mint.key()),
-        PieError::InvalidComponent
-    );
-    require!(
-        ctx.accounts.jupiter_program.key() == JUPITER_PROGRAM_ID,
-        PieError::InvalidJupiterProgram
-    );
-
-    let user_fund = &mut ctx.accounts.user_fund;
-
-    let balance_in_before = ctx.accounts.vault_token_source.amount;
-    let balance_out_before = ctx.accounts.vault_token_destination.amount;
-
-    // Prepare accounts for jupiter program
-    let accounts: Vec<AccountMeta> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| {
-            let is_signer = acc.key == &ctx.accounts.basket_config.key();
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer,
-                is_writable: acc.is_writable,
-            }
-        })
-        .collect();
-
-    let accounts_infos: Vec<AccountInfo> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .collect();
-
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        BASKET_CONFIG,
-        &ctx.accounts.basket_config.id.to_be_bytes(),
-        &[ctx.accounts.basket_config.bump],
-    ]];
-
-    // Invoke jupiter program
-    invoke_signed(
-        &Instruction {
-            program_id: ctx.accounts.jupiter_program.key(),
-            accounts,
-            data,
-        },
-        &accounts_infos,
-        signer_seeds,
-    )?;
-
-    ctx.accounts.vault_token_source.reload()?;
-    ctx.accounts.vault_token_destination.reload()?;
-
-    let (amount_swapped, amount_received) = calculate_amounts_swapped_and_received(
-        &ctx.accounts.vault_token_source,
-        &ctx.accounts.vault_token_destination,
-        balance_in_before,
-        balance_out_before,
-    )?;
-
-    require!(amount_swapped > 0, PieError::InvalidSwapResult);
-    require!(amount_received > 0, PieError::InvalidSwapResult);
-
-    // Remove input token from user fund
-    // It will throw error if amount_swapped is greater than the balance of the user fund
-    user_fund.remove_component(NATIVE_MINT, amount_swapped)?;
-
-    // Add output token to user fund
-    user_fund.upsert_component(
-        ctx.accounts.vault_token_destination.mint.key(),
-        amount_received,
+    let accounts = ctx.accounts;
+    let outcome = buy_component_with_swap(
+        &accounts.user.key(),
+        &mut accounts.user_fund,
+        &accounts.basket_config,
+        &accounts.program_state,
+        accounts.allowlist_entry.as_deref(),
+        &accounts.vault_token_source,
+        &accounts.vault_token_destination,
+        &accounts.jupiter_program,
+        ctx.remaining_accounts,
+        &SwapRequest::Jupiter(JupiterSwap { data }),
     )?;
 
     emit!(BuyComponentJupiterEvent {
-        basket_id: ctx.accounts.basket_config.id,
-        user: ctx.accounts.user.key(),
-        mint: ctx.accounts.vault_token_destination.mint.key(),
-        amount_swapped,
-        amount_received,
+        basket_id: accounts.basket_config.id,
+        user: accounts.user.key(),
+        mint: accounts.vault_token_destination.mint,
+        amount_swapped: outcome.amount_in,
+        amount_received: outcome.amount_out,
     });
 
     Ok(())
@@ -0,0 +1,69 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{
+    error::PieError,
+    states::{AllowlistEntry, AllowlistMode},
+    utils::verify_merkle_proof,
+    BasketConfig, ALLOWLIST_ENTRY, BASKET_CONFIG,
+};
+
+#[derive(Accounts)]
+pub struct ClaimAllowlistEntryContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = AllowlistEntry::INIT_SPACE,
+        seeds = [ALLOWLIST_ENTRY, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Box<Account<'info, AllowlistEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ClaimAllowlistEntryEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+}
+
+/// Creates the allowlist entry of the user from a Merkle proof against the basket
+/// `allowlist_root`. Leaves are the sha256 hash of the user address.
+pub fn claim_allowlist_entry(
+    ctx: Context<ClaimAllowlistEntryContext>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let basket_config = &ctx.accounts.basket_config;
+    require!(
+        basket_config.allowlist_mode == AllowlistMode::Merkle,
+        PieError::InvalidAllowlist
+    );
+
+    let user = ctx.accounts.user.key();
+    let leaf = hashv(&[user.as_ref()]).to_bytes();
+    require!(
+        verify_merkle_proof(&proof, &basket_config.allowlist_root, leaf),
+        PieError::NotAllowlisted
+    );
+
+    let allowlist_entry = &mut ctx.accounts.allowlist_entry;
+    allowlist_entry.bump = ctx.bumps.allowlist_entry;
+    allowlist_entry.basket_id = basket_config.id;
+    allowlist_entry.user = user;
+    allowlist_entry.payer = user;
+
+    emit!(ClaimAllowlistEntryEvent {
+        basket_id: basket_config.id,
+        user,
+    });
+    Ok(())
+}
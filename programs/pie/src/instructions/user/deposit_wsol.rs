@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token::Token, token_interface::TokenAccount};
 
 use crate::{
-    constant::USER_FUND, error::PieError, states::BasketState, utils::{calculate_fee_amount, transfer_fees, transfer_from_user_to_pool_vault}, BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE
+    constant::{PAUSE_MINT, USER_FUND}, error::PieError, states::{AllowlistEntry, BasketState}, utils::{calculate_fee_amount, transfer_fees, transfer_from_user_to_pool_vault}, BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE
 };
 
 #[derive(Accounts)]
@@ -57,6 +57,9 @@ pub struct DepositWsolContext<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// Allowlist entry of the user, required when the basket has an allowlist
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
 }
 
 #[event]
@@ -76,6 +79,13 @@ pub fn deposit_wsol(ctx: Context<DepositWsolContext>, amount: u64) -> Result<()>
         ctx.accounts.basket_config.state == BasketState::Active,
         PieError::OnlyDefaultState
     );
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_MINT)?;
+    ctx.accounts.basket_config.check_allowlisted(
+        &ctx.accounts.user.key(),
+        ctx.accounts.allowlist_entry.as_deref(),
+    )?;
 
     let user_fund = &mut ctx.accounts.user_fund;
 
@@ -2,14 +2,16 @@ use anchor_lang::prelude::*;
 use anchor_spl::{ token::{ mint_to, MintTo, Token, TokenAccount }, token_interface::Mint };
 
 use crate::{
-    constant::{ USER_BALANCE, USER_FUND },
+    constant::{ PAUSE_MINT, USER_BALANCE, USER_FUND, USER_MINT_RECORD },
     error::PieError,
-    states::{BasketState, UserBalance},
+    states::{AllowlistEntry, BasketComponent, BasketState, UserBalance, UserComponent, UserMintRecord},
     utils::Calculator,
     BasketConfig,
+    ProgramState,
     UserFund,
     BASKET_CONFIG,
     BASKET_MINT,
+    PROGRAM_STATE,
 };
 
 #[derive(Accounts)]
@@ -24,6 +26,12 @@ pub struct MintBasketTokenContext<'info> {
     )]
     pub basket_config: Box<Account<'info, BasketConfig>>,
 
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
     #[account(
         mut,
         seeds = [USER_FUND, &user.key().as_ref(), &basket_config.id.to_be_bytes()],
@@ -51,8 +59,20 @@ pub struct MintBasketTokenContext<'info> {
     )]
     pub user_basket_token_account: Box<Account<'info, TokenAccount>>,
 
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserMintRecord::INIT_SPACE,
+        seeds = [USER_MINT_RECORD, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub user_mint_record: Box<Account<'info, UserMintRecord>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// Allowlist entry of the user, required when the basket has an allowlist
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
 }
 
 #[event]
@@ -63,7 +83,7 @@ pub struct MintBasketTokenEvent {
     pub amount: u64,
 }
 
-pub fn mint_basket_token(ctx: Context<MintBasketTokenContext>) -> Result<()> {
+pub fn mint_basket_token(ctx: Context<MintBasketTokenContext>, min_mint_amount: u64) -> Result<()> {
     let user_fund = &mut ctx.accounts.user_fund;
     let basket_config = &mut ctx.accounts.basket_config;
     let user_balance = &mut ctx.accounts.user_balance;
@@ -72,33 +92,28 @@ pub fn mint_basket_token(ctx: Context<MintBasketTokenContext>) -> Result<()> {
         basket_config.state == BasketState::Active,
         PieError::OnlyDefaultState
     );
-
-    let mut mint_amount = u64::MAX;
-
-    for token_config in basket_config.components.iter() {
-        if let Some(user_asset) = user_fund.components.iter().find(|a| a.mint == token_config.mint) {
-            let possible_mint_amount = calculate_possible_mint_amount(
-                user_asset.amount,
-                token_config.quantity_in_sys_decimal
-            ).unwrap();
-            mint_amount = mint_amount.min(possible_mint_amount.try_into().unwrap());
-        } else {
-            return Err(PieError::ComponentNotFound.into());
-        }
-    }
-
-    require!(mint_amount > 0, PieError::InvalidAmount);
-
-    for token_config in basket_config.components.iter() {
+    basket_config.check_not_paused(&ctx.accounts.program_state, PAUSE_MINT)?;
+    basket_config.check_allowlisted(&ctx.accounts.user.key(), ctx.accounts.allowlist_entry.as_deref())?;
+
+    let (mint_amount, amounts_left) = calculate_mint_amounts(
+        &basket_config.components,
+        &user_fund.components
+    )?;
+
+    check_min_mint_amount(mint_amount, min_mint_amount)?;
+    let net_minted_after = ctx.accounts.user_mint_record.record_mint(
+        ctx.bumps.user_mint_record,
+        basket_config.id,
+        ctx.accounts.user.key(),
+        mint_amount
+    )?;
+    basket_config.check_mint_limits(
+        ctx.accounts.basket_mint.supply.checked_add(mint_amount).ok_or(PieError::MaxSupplyExceeded)?,
+        net_minted_after
+    )?;
+
+    for (token_config, amount_left) in basket_config.components.iter().zip(amounts_left) {
         if let Some(asset) = user_fund.components.iter_mut().find(|a| a.mint == token_config.mint) {
-            let amount_to_deduct_in_raw_decimal = calculate_deduct_amount(
-                mint_amount.into(),
-                token_config.quantity_in_sys_decimal
-            )?;
-            let amount_left = asset.amount
-                .checked_sub(amount_to_deduct_in_raw_decimal)
-                .ok_or(PieError::InsufficientBalance)?;
-
             asset.amount = 0;
 
             if amount_left > 0 {
@@ -112,28 +127,7 @@ pub fn mint_basket_token(ctx: Context<MintBasketTokenContext>) -> Result<()> {
     user_fund.components.retain(|component: &crate::states::UserComponent| component.amount > 0);
 
     // Reallocate user_balance
-    let required_space = UserBalance::size_for_len(user_balance.balances.len());
-    let rent = Rent::get()?;
-    let new_minimum_balance = rent.minimum_balance(required_space);
-    let current_balance = user_balance.to_account_info().lamports();
-
-    if current_balance < new_minimum_balance {
-        let additional_rent = new_minimum_balance
-            .checked_sub(current_balance)
-            .ok_or(PieError::InvalidAmount)?;
-
-        // Transfer additional rent using system program
-        anchor_lang::solana_program::program::invoke(
-            &anchor_lang::solana_program::system_instruction::transfer(
-                &ctx.accounts.user.key(),
-                &user_balance.to_account_info().key(),
-                additional_rent
-            ),
-            &[ctx.accounts.user.to_account_info(), user_balance.to_account_info()]
-        )?;
-    }
-
-    user_balance.to_account_info().realloc(required_space, false)?;
+    user_balance.realloc_to_fit(user_balance.to_account_info(), ctx.accounts.user.to_account_info())?;
 
     let signer: &[&[&[u8]]] = &[
         &[BASKET_CONFIG, &basket_config.id.to_be_bytes(), &[ctx.accounts.basket_config.bump]],
@@ -163,7 +157,46 @@ pub fn mint_basket_token(ctx: Context<MintBasketTokenContext>) -> Result<()> {
     Ok(())
 }
 
-fn calculate_deduct_amount(
+/// Returns the basket token amount minted from `user_components` and the amount of each
+/// basket component, in `components` order, left over for the user balance.
+pub(crate) fn calculate_mint_amounts(
+    components: &[BasketComponent],
+    user_components: &[UserComponent]
+) -> Result<(u64, Vec<u64>)> {
+    let mut mint_amount = u64::MAX;
+
+    for token_config in components.iter() {
+        if let Some(user_asset) = user_components.iter().find(|a| a.mint == token_config.mint) {
+            let possible_mint_amount = calculate_possible_mint_amount(
+                user_asset.amount,
+                token_config.quantity_in_sys_decimal
+            ).unwrap();
+            mint_amount = mint_amount.min(possible_mint_amount.try_into().unwrap());
+        } else {
+            return Err(PieError::ComponentNotFound.into());
+        }
+    }
+
+    let mut amounts_left = Vec::with_capacity(components.len());
+    for token_config in components.iter() {
+        let user_asset = user_components
+            .iter()
+            .find(|a| a.mint == token_config.mint)
+            .ok_or(PieError::ComponentNotFound)?;
+        let amount_to_deduct_in_raw_decimal = calculate_deduct_amount(
+            mint_amount.into(),
+            token_config.quantity_in_sys_decimal
+        )?;
+        let amount_left = user_asset.amount
+            .checked_sub(amount_to_deduct_in_raw_decimal)
+            .ok_or(PieError::InsufficientBalance)?;
+        amounts_left.push(amount_left);
+    }
+
+    Ok((mint_amount, amounts_left))
+}
+
+pub(crate) fn calculate_deduct_amount(
     basket_token_amount: u128,
     quantity_in_sys_decimal: u128
 ) -> Result<u64> {
@@ -171,7 +204,7 @@ fn calculate_deduct_amount(
     Ok(Calculator::restore_raw_decimal_round_up(amount_to_deduct))
 }
 
-fn calculate_possible_mint_amount(
+pub(crate) fn calculate_possible_mint_amount(
     user_asset_amount: u64,
     quantity_in_sys_decimal: u128
 ) -> Result<u128> {
@@ -180,6 +213,13 @@ fn calculate_possible_mint_amount(
     Ok(user_amount_in_system_decimal.checked_div(quantity_in_sys_decimal).unwrap())
 }
 
+/// Rejects empty mints and mints below the `min_mint_amount` slippage guard.
+pub(crate) fn check_min_mint_amount(mint_amount: u64, min_mint_amount: u64) -> Result<()> {
+    require!(mint_amount > 0, PieError::InvalidAmount);
+    require!(mint_amount >= min_mint_amount, PieError::MintSlippageExceeded);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +228,32 @@ mod tests {
     fn test_calculate_deduct_amount() {
         assert_eq!(calculate_deduct_amount(1_000_000_000u128, 1_000u128).unwrap(), 1_000_000u64);
     }
+
+    #[test]
+    fn test_calculate_mint_amounts() {
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let components = [
+            BasketComponent { mint: mint_a, quantity_in_sys_decimal: 2_000_000, decimals: 6, target_weight_bp: 0 },
+            BasketComponent { mint: mint_b, quantity_in_sys_decimal: 1_500_000, decimals: 6, target_weight_bp: 0 },
+        ];
+        let user_components = [
+            UserComponent { mint: mint_b, amount: 1_000 },
+            UserComponent { mint: mint_a, amount: 1_500 },
+        ];
+
+        // mint_b limits the mint to 666, leaving 1500 - 1332 of mint_a and 1000 - 999 of mint_b
+        let (mint_amount, amounts_left) = calculate_mint_amounts(&components, &user_components).unwrap();
+        assert_eq!(mint_amount, 666);
+        assert_eq!(amounts_left, vec![168, 1]);
+
+        assert!(calculate_mint_amounts(&components, &user_components[..1]).is_err());
+    }
+
+    #[test]
+    fn test_check_min_mint_amount() {
+        assert!(check_min_mint_amount(666, 0).is_ok());
+        assert!(check_min_mint_amount(666, 666).is_ok());
+        assert_eq!(check_min_mint_amount(666, 667), err!(PieError::MintSlippageExceeded));
+        assert_eq!(check_min_mint_amount(0, 0), err!(PieError::InvalidAmount));
+    }
 }
@@ -0,0 +1,340 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::{self, mint_to, MintTo, Token},
+    token_2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    constant::{
+        BASKET_CONFIG, NATIVE_MINT, PAUSE_MINT, PAUSE_SWAP, PROGRAM_STATE, USER_BALANCE,
+        USER_MINT_RECORD,
+    },
+    error::PieError,
+    instructions::{
+        calculate_deduct_amount, calculate_possible_mint_amount, check_min_mint_amount,
+    },
+    states::{AllowlistEntry, BasketState, UserBalance, UserMintRecord},
+    utils::{
+        calculate_fee_amount, execute_jupiter_swap, transfer_from_pool_vault_to_user,
+        transfer_from_user_to_pool_vault, SwapBounds,
+    },
+    BasketConfig, ProgramState,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct JupiterSwapRoute {
+    /// Jupiter instruction data
+    pub data: Vec<u8>,
+    /// Number of Jupiter accounts in `remaining_accounts` used by this route
+    pub accounts_len: u8,
+}
+
+#[derive(Accounts)]
+pub struct MintBasketTokenFromSolContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [USER_BALANCE, &user.key().as_ref()],
+        bump = user_balance.bump,
+    )]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+
+    #[account(
+        mut,
+        token::mint = NATIVE_MINT,
+        token::authority = user
+    )]
+    pub user_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = NATIVE_MINT,
+        associated_token::authority = basket_config
+    )]
+    pub vault_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::authority = user,
+        token::mint = basket_config.mint
+    )]
+    pub user_basket_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserMintRecord::INIT_SPACE,
+        seeds = [USER_MINT_RECORD, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub user_mint_record: Box<Account<'info, UserMintRecord>>,
+
+    /// CHECK: Platform fee wallet that receives the fee
+    #[account(mut, address = program_state.platform_fee_wallet)]
+    pub platform_fee_wallet: AccountInfo<'info>,
+
+    /// CHECK: Creator fee wallet that receives the fee
+    #[account(mut, address = basket_config.creator)]
+    pub creator_fee_wallet: AccountInfo<'info>,
+
+    /// CHECK: Jupiter program will be checked in require
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// Allowlist entry of the user, required when the basket has an allowlist
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+    // remaining accounts, for each route:
+    // vault token account of the component bought by the route
+    // `accounts_len` Jupiter accounts
+}
+
+#[event]
+pub struct MintBasketTokenFromSolEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+    pub basket_mint: Pubkey,
+    pub amount_deposited: u64,
+    pub amount_swapped: u64,
+    pub amount: u64,
+    pub creator_fee: u64,
+    pub platform_fee: u64,
+}
+
+/// Deposits `amount` WSOL, buys every component with the given Jupiter routes and mints
+/// basket tokens in a single instruction.
+/// Routes follow `basket_config.components` order and skip components with zero quantity.
+/// Unspent WSOL is returned to the user and component leftovers are recorded in `UserBalance`.
+pub fn mint_basket_token_from_sol<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, MintBasketTokenFromSolContext<'info>>,
+    amount: u64,
+    routes: Vec<JupiterSwapRoute>,
+    min_basket_tokens_out: u64,
+) -> Result<()> {
+    require!(amount > 0, PieError::InvalidAmount);
+    require!(
+        ctx.accounts.basket_config.state == BasketState::Active,
+        PieError::OnlyDefaultState
+    );
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_MINT | PAUSE_SWAP)?;
+    ctx.accounts.basket_config.check_allowlisted(
+        &ctx.accounts.user.key(),
+        ctx.accounts.allowlist_entry.as_deref(),
+    )?;
+
+    let components: Vec<_> = ctx
+        .accounts
+        .basket_config
+        .components
+        .iter()
+        .filter(|component| component.quantity_in_sys_decimal > 0)
+        .cloned()
+        .collect();
+    require!(
+        !components.is_empty() && routes.len() == components.len(),
+        PieError::InvalidRemainingAccounts
+    );
+
+    let (platform_fee_amount, creator_fee_amount) = calculate_fee_amount(
+        ctx.accounts.program_state.platform_fee_bp,
+        ctx.accounts.basket_config.creator_fee_bp,
+        amount,
+    )?;
+
+    for (fee_wallet, fee_amount) in [
+        (
+            ctx.accounts.platform_fee_wallet.to_account_info(),
+            platform_fee_amount,
+        ),
+        (
+            ctx.accounts.creator_fee_wallet.to_account_info(),
+            creator_fee_amount,
+        ),
+    ] {
+        if fee_amount > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: fee_wallet,
+                    },
+                ),
+                fee_amount,
+            )?;
+        }
+    }
+
+    transfer_from_user_to_pool_vault(
+        &ctx.accounts.user_wsol_account.to_account_info(),
+        &ctx.accounts.vault_wsol_account.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.vault_wsol_account.reload()?;
+
+    let basket_config_key = ctx.accounts.basket_config.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BASKET_CONFIG,
+        &ctx.accounts.basket_config.id.to_be_bytes(),
+        &[ctx.accounts.basket_config.bump],
+    ]];
+
+    let mut amounts_received = Vec::with_capacity(components.len());
+    let mut total_amount_swapped: u64 = 0;
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    for (component, route) in components.iter().zip(routes) {
+        let accounts_len = route.accounts_len as usize;
+        require!(
+            remaining_accounts.len() > accounts_len,
+            PieError::InvalidRemainingAccounts
+        );
+        let (vault_token_destination, rest) = remaining_accounts.split_first().unwrap();
+        let (jupiter_accounts, rest) = rest.split_at(accounts_len);
+        remaining_accounts = rest;
+
+        let token_program = *vault_token_destination.owner;
+        require!(
+            token_program == token::ID || token_program == token_2022::ID,
+            PieError::InvalidTokenProgram
+        );
+        require!(
+            *vault_token_destination.key
+                == get_associated_token_address_with_program_id(
+                    &basket_config_key,
+                    &component.mint,
+                    &token_program,
+                ),
+            PieError::InvalidComponent
+        );
+
+        let outcome = execute_jupiter_swap(
+            &ctx.accounts.basket_config,
+            &ctx.accounts.jupiter_program,
+            jupiter_accounts,
+            &ctx.accounts.vault_wsol_account.to_account_info(),
+            vault_token_destination,
+            route.data,
+            SwapBounds::default(),
+        )?;
+
+        total_amount_swapped = total_amount_swapped.checked_add(outcome.amount_in).unwrap();
+        amounts_received.push(outcome.amount_out);
+    }
+
+    // Routes can only spend the WSOL deposited in this instruction
+    require!(
+        total_amount_swapped <= amount,
+        PieError::InsufficientBalance
+    );
+
+    let mut mint_amount = u64::MAX;
+    for (component, amount_received) in components.iter().zip(amounts_received.iter()) {
+        let possible_mint_amount =
+            calculate_possible_mint_amount(*amount_received, component.quantity_in_sys_decimal)?;
+        mint_amount = mint_amount.min(
+            possible_mint_amount
+                .try_into()
+                .map_err(|_| PieError::ConversionFailure)?,
+        );
+    }
+
+    check_min_mint_amount(mint_amount, min_basket_tokens_out)?;
+    let net_minted_after = ctx.accounts.user_mint_record.record_mint(
+        ctx.bumps.user_mint_record,
+        ctx.accounts.basket_config.id,
+        ctx.accounts.user.key(),
+        mint_amount,
+    )?;
+    ctx.accounts.basket_config.check_mint_limits(
+        ctx.accounts
+            .basket_mint
+            .supply
+            .checked_add(mint_amount)
+            .ok_or(PieError::MaxSupplyExceeded)?,
+        net_minted_after,
+    )?;
+
+    // Record component leftovers in the user balance
+    let basket_id = ctx.accounts.basket_config.id;
+    let user_balance = &mut ctx.accounts.user_balance;
+    for (component, amount_received) in components.iter().zip(amounts_received.iter()) {
+        let amount_to_deduct =
+            calculate_deduct_amount(mint_amount.into(), component.quantity_in_sys_decimal)?;
+        let amount_left = amount_received
+            .checked_sub(amount_to_deduct)
+            .ok_or(PieError::InsufficientBalance)?;
+
+        if amount_left > 0 {
+            user_balance.upsert_balance(basket_id, component.mint, amount_left)?;
+        }
+    }
+    user_balance.realloc_to_fit(
+        user_balance.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+    )?;
+
+    // Return unspent WSOL to the user
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_wsol_account.to_account_info(),
+        &ctx.accounts.user_wsol_account.to_account_info(),
+        &ctx.accounts.basket_config.to_account_info(),
+        &ctx.accounts.token_program,
+        amount - total_amount_swapped,
+        signer_seeds,
+    )?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.basket_mint.to_account_info(),
+                to: ctx.accounts.user_basket_token_account.to_account_info(),
+                authority: ctx.accounts.basket_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        mint_amount,
+    )?;
+
+    emit!(MintBasketTokenFromSolEvent {
+        basket_id,
+        user: ctx.accounts.user.key(),
+        basket_mint: ctx.accounts.basket_mint.key(),
+        amount_deposited: amount,
+        amount_swapped: total_amount_swapped,
+        amount: mint_amount,
+        creator_fee: creator_fee_amount,
+        platform_fee: platform_fee_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, MintTo, Token, TokenAccount},
+    token_interface::{Mint, Token2022},
+};
+
+use crate::{
+    constant::{BASKET_CONFIG, PAUSE_MINT, PROGRAM_STATE, USER_MINT_RECORD},
+    error::PieError,
+    instructions::calculate_deduct_amount,
+    states::{AllowlistEntry, BasketState, UserMintRecord},
+    utils::{
+        calculate_fee_amount, transfer_checked_from_user_to_pool_vault,
+        validate_in_kind_component_accounts,
+    },
+    BasketConfig, ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct MintBasketTokenInKindContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::authority = user,
+        token::mint = basket_config.mint
+    )]
+    pub user_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserMintRecord::INIT_SPACE,
+        seeds = [USER_MINT_RECORD, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub user_mint_record: Box<Account<'info, UserMintRecord>>,
+
+    /// CHECK: Platform fee wallet that receives the fee
+    #[account(address = program_state.platform_fee_wallet)]
+    pub platform_fee_wallet: AccountInfo<'info>,
+
+    /// CHECK: Creator wallet that receives the fee
+    #[account(address = basket_config.creator)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = basket_mint,
+        associated_token::authority = platform_fee_wallet,
+    )]
+    pub platform_fee_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = basket_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_program_2022: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Allowlist entry of the user, required when the basket has an allowlist
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+    // remaining accounts, for each component in `basket_config.components` order:
+    // component mint
+    // user token account
+    // vault token account (ATA of basket_config)
+}
+
+#[event]
+pub struct MintBasketTokenInKindEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+    pub basket_mint: Pubkey,
+    pub amount: u64,
+    pub creator_fee: u64,
+    pub platform_fee: u64,
+}
+
+/// Deposits every component straight from the user's token accounts and mints `amount`
+/// basket tokens. Platform and creator fees are charged in basket tokens.
+pub fn mint_basket_token_in_kind<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, MintBasketTokenInKindContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, PieError::InvalidAmount);
+
+    let basket_config = &ctx.accounts.basket_config;
+    require!(
+        basket_config.state == BasketState::Active,
+        PieError::OnlyDefaultState
+    );
+    basket_config.check_not_paused(&ctx.accounts.program_state, PAUSE_MINT)?;
+    basket_config.check_allowlisted(
+        &ctx.accounts.user.key(),
+        ctx.accounts.allowlist_entry.as_deref(),
+    )?;
+    require!(
+        ctx.remaining_accounts.len() == basket_config.components.len() * 3,
+        PieError::InvalidRemainingAccounts
+    );
+
+    for (component, accounts) in basket_config
+        .components
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(3))
+    {
+        let (mint, user_token_account, vault_token_account) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        let (decimals, token_program) = validate_in_kind_component_accounts(
+            &basket_config.key(),
+            &ctx.accounts.user.key(),
+            &component.mint,
+            mint,
+            user_token_account,
+            vault_token_account,
+        )?;
+
+        let token_program = if token_program == ctx.accounts.token_program.key() {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.accounts.token_program_2022.to_account_info()
+        };
+
+        // Same rounding as `mint_basket_token`: components are always rounded up
+        let deposit_amount =
+            calculate_deduct_amount(amount.into(), component.quantity_in_sys_decimal)?;
+
+        transfer_checked_from_user_to_pool_vault(
+            user_token_account,
+            vault_token_account,
+            mint,
+            &ctx.accounts.user.to_account_info(),
+            &token_program,
+            deposit_amount,
+            decimals,
+        )?;
+    }
+
+    let (platform_fee_amount, creator_fee_amount) = calculate_fee_amount(
+        ctx.accounts.program_state.platform_fee_bp,
+        basket_config.creator_fee_bp,
+        amount,
+    )?;
+    let user_amount = amount
+        .checked_sub(platform_fee_amount)
+        .unwrap()
+        .checked_sub(creator_fee_amount)
+        .ok_or(PieError::InvalidFee)?;
+    require!(user_amount > 0, PieError::InvalidAmount);
+    let net_minted_after = ctx.accounts.user_mint_record.record_mint(
+        ctx.bumps.user_mint_record,
+        basket_config.id,
+        ctx.accounts.user.key(),
+        user_amount,
+    )?;
+    basket_config.check_mint_limits(
+        ctx.accounts
+            .basket_mint
+            .supply
+            .checked_add(amount)
+            .ok_or(PieError::MaxSupplyExceeded)?,
+        net_minted_after,
+    )?;
+
+    let signer: &[&[&[u8]]] = &[&[
+        BASKET_CONFIG,
+        &basket_config.id.to_be_bytes(),
+        &[basket_config.bump],
+    ]];
+
+    for (to, mint_amount) in [
+        (
+            ctx.accounts.platform_fee_basket_token_account.to_account_info(),
+            platform_fee_amount,
+        ),
+        (
+            ctx.accounts.creator_basket_token_account.to_account_info(),
+            creator_fee_amount,
+        ),
+        (
+            ctx.accounts.user_basket_token_account.to_account_info(),
+            user_amount,
+        ),
+    ] {
+        if mint_amount == 0 {
+            continue;
+        }
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.basket_mint.to_account_info(),
+                    to,
+                    authority: basket_config.to_account_info(),
+                },
+                signer,
+            ),
+            mint_amount,
+        )?;
+    }
+
+    emit!(MintBasketTokenInKindEvent {
+        basket_id: basket_config.id,
+        user: ctx.accounts.user.key(),
+        basket_mint: ctx.accounts.basket_mint.key(),
+        amount: user_amount,
+        creator_fee: creator_fee_amount,
+        platform_fee: platform_fee_amount,
+    });
+
+    Ok(())
+}
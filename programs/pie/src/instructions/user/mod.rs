@@ -1,6 +1,9 @@
 pub mod deposit_wsol;
 pub use deposit_wsol::*;
 
+pub mod buy_component;
+pub use buy_component::*;
+
 pub mod buy_component_jupiter;
 pub use buy_component_jupiter::*;
 
@@ -10,6 +13,9 @@ pub use mint_basket_token::*;
 pub mod redeem_basket_token;
 pub use redeem_basket_token::*;
 
+pub mod sell_component;
+pub use sell_component::*;
+
 pub mod sell_component_jupiter;
 pub use sell_component_jupiter::*;
 
@@ -21,3 +27,27 @@ pub use initialize_user_balance::*;
 
 pub mod withdraw_user_balance;
 pub use withdraw_user_balance::*;
+
+pub mod mint_basket_token_in_kind;
+pub use mint_basket_token_in_kind::*;
+
+pub mod redeem_basket_token_in_kind;
+pub use redeem_basket_token_in_kind::*;
+
+pub mod mint_basket_token_from_sol;
+pub use mint_basket_token_from_sol::*;
+
+pub mod redeem_basket_token_to_sol;
+pub use redeem_basket_token_to_sol::*;
+
+pub mod quote_nav;
+pub use quote_nav::*;
+
+pub mod quote_mint;
+pub use quote_mint::*;
+
+pub mod quote_redeem;
+pub use quote_redeem::*;
+
+pub mod claim_allowlist_entry;
+pub use claim_allowlist_entry::*;
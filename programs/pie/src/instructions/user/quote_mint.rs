@@ -0,0 +1,57 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+
+use crate::{
+    constant::USER_FUND, error::PieError, instructions::calculate_mint_amounts,
+    states::BasketState, BasketConfig, UserFund, BASKET_CONFIG,
+};
+
+#[derive(Accounts)]
+pub struct QuoteMintContext<'info> {
+    /// CHECK: only used to derive the user fund
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        seeds = [USER_FUND, &user.key().as_ref(), &basket_config.id.to_be_bytes()],
+        bump = user_fund.bump
+    )]
+    pub user_fund: Box<Account<'info, UserFund>>,
+}
+
+/// Result of `quote_mint`, matching what `mint_basket_token` would do for the user fund.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MintQuote {
+    pub mint_amount: u64,
+    /// Amount of each component, in `basket_config.components` order, that would be
+    /// recorded in the user balance
+    pub amounts_left: Vec<u64>,
+}
+
+/// Returns the `MintQuote` of the user fund through the transaction return data.
+pub fn quote_mint(ctx: Context<QuoteMintContext>) -> Result<()> {
+    let basket_config = &ctx.accounts.basket_config;
+    require!(
+        basket_config.state == BasketState::Active,
+        PieError::OnlyDefaultState
+    );
+
+    let (mint_amount, amounts_left) = calculate_mint_amounts(
+        &basket_config.components,
+        &ctx.accounts.user_fund.components,
+    )?;
+
+    set_return_data(
+        &MintQuote {
+            mint_amount,
+            amounts_left,
+        }
+        .try_to_vec()?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    utils::{calculate_basket_nav, load_component_prices},
+    BasketConfig, BASKET_CONFIG,
+};
+
+#[derive(Accounts)]
+pub struct QuoteNavContext<'info> {
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(address = basket_config.mint)]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+    // remaining accounts, for each component in `basket_config.components` order:
+    // price feed accounts of the component mint, Pyth or pool TWAP (see `price_accounts_len`)
+}
+
+/// Basket valuation returned by `quote_nav`, in USD scaled by 10^VALUE_DECIMALS.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NavQuote {
+    pub total_supply: u64,
+    pub aum: u128,
+    pub nav_per_token: u128,
+}
+
+#[event]
+pub struct QuoteNavEvent {
+    pub basket_id: u64,
+    pub mint: Pubkey,
+    pub total_supply: u64,
+    pub aum: u128,
+    pub nav_per_token: u128,
+    pub timestamp: i64,
+}
+
+/// Computes the basket NAV from the component price feeds and returns it as a `NavQuote`
+/// through the transaction return data. Each component is priced by the source of its feed.
+pub fn quote_nav<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, QuoteNavContext<'info>>,
+) -> Result<()> {
+    let basket_config = &ctx.accounts.basket_config;
+    let total_supply = ctx.accounts.basket_mint.supply;
+    let now = Clock::get()?.unix_timestamp;
+
+    let prices = load_component_prices(&basket_config.components, ctx.remaining_accounts, now)?;
+    let nav = calculate_basket_nav(&basket_config.components, &prices, total_supply, None)?;
+
+    let quote = NavQuote {
+        total_supply,
+        aum: nav.aum,
+        nav_per_token: nav.nav_per_token,
+    };
+    set_return_data(&quote.try_to_vec()?);
+
+    emit!(QuoteNavEvent {
+        basket_id: basket_config.id,
+        mint: basket_config.mint,
+        total_supply,
+        aum: nav.aum,
+        nav_per_token: nav.nav_per_token,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+
+use crate::{
+    error::PieError, instructions::calculate_redeem_amount, states::BasketState, BasketConfig,
+    BASKET_CONFIG,
+};
+
+#[derive(Accounts)]
+pub struct QuoteRedeemContext<'info> {
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+}
+
+/// Result of `quote_redeem`, matching what `redeem_basket_token` would credit to the user fund.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RedeemQuote {
+    /// Amount of each component, in `basket_config.components` order
+    pub amounts_out: Vec<u64>,
+}
+
+/// Returns the `RedeemQuote` of burning `amount` basket tokens through the transaction
+/// return data.
+pub fn quote_redeem(ctx: Context<QuoteRedeemContext>, amount: u64) -> Result<()> {
+    require!(amount > 0, PieError::InvalidAmount);
+    let basket_config = &ctx.accounts.basket_config;
+    require!(
+        basket_config.state != BasketState::Rebalancing,
+        PieError::RebalancingInProgress
+    );
+
+    let amounts_out = basket_config
+        .components
+        .iter()
+        .map(|component| calculate_redeem_amount(amount, component.quantity_in_sys_decimal))
+        .collect();
+
+    set_return_data(&RedeemQuote { amounts_out }.try_to_vec()?);
+
+    Ok(())
+}
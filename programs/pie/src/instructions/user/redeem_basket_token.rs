@@ -5,7 +5,7 @@ use anchor_spl::{
 };
 
 use crate::{
-    constant::USER_FUND, error::PieError, states::BasketState, utils::Calculator, BasketConfig,
+    constant::{PAUSE_REDEEM, USER_FUND, USER_MINT_RECORD}, error::PieError, states::{BasketComponent, BasketState, UserMintRecord}, utils::Calculator, BasketConfig,
     ProgramState, UserFund, PROGRAM_STATE,
 };
 
@@ -51,6 +51,14 @@ pub struct RedeemBasketTokenContext<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// Mint record of the user, decreased by the redeemed amount when passed
+    #[account(
+        mut,
+        seeds = [USER_MINT_RECORD, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump = user_mint_record.bump
+    )]
+    pub user_mint_record: Option<Account<'info, UserMintRecord>>,
 }
 
 #[event]
@@ -61,7 +69,12 @@ pub struct RedeemBasketTokenEvent {
     pub amount: u64,
 }
 
-pub fn redeem_basket_token(ctx: Context<RedeemBasketTokenContext>, amount: u64) -> Result<()> {
+/// `min_amounts_out` holds the minimum amount of each component, in `basket_config.components` order.
+pub fn redeem_basket_token(
+    ctx: Context<RedeemBasketTokenContext>,
+    amount: u64,
+    min_amounts_out: Vec<u64>,
+) -> Result<()> {
     // Validate amount
     require!(amount > 0, PieError::InvalidAmount);
     let user_fund = &mut ctx.accounts.user_fund;
@@ -71,6 +84,8 @@ pub fn redeem_basket_token(ctx: Context<RedeemBasketTokenContext>, amount: u64)
         basket_config.state != BasketState::Rebalancing,
         PieError::RebalancingInProgress
     );
+    basket_config.check_not_paused(&ctx.accounts.program_state, PAUSE_REDEEM)?;
+    let amounts_return = calculate_redeem_amounts(amount, &basket_config.components, &min_amounts_out)?;
 
     // Validate that the user has enough tokens to burn
     require!(
@@ -90,16 +105,12 @@ pub fn redeem_basket_token(ctx: Context<RedeemBasketTokenContext>, amount: u64)
 
     burn(burn_basket_ctx, amount)?;
 
-    for token_config in basket_config.components.iter() {
-        let amount_return: u128 = token_config
-            .quantity_in_sys_decimal
-            .checked_mul(amount.into())
-            .unwrap();
+    if let Some(user_mint_record) = ctx.accounts.user_mint_record.as_mut() {
+        user_mint_record.record_redeem(amount);
+    }
 
-        user_fund.upsert_component(
-            token_config.mint,
-            Calculator::restore_raw_decimal(amount_return),
-        )?;
+    for (token_config, amount_return) in basket_config.components.iter().zip(amounts_return) {
+        user_fund.upsert_component(token_config.mint, amount_return)?;
     }
 
     emit!(RedeemBasketTokenEvent {
@@ -111,3 +122,59 @@ pub fn redeem_basket_token(ctx: Context<RedeemBasketTokenContext>, amount: u64)
 
     Ok(())
 }
+
+/// Amount of a component with `quantity_in_sys_decimal` returned for `amount` burned basket tokens.
+pub(crate) fn calculate_redeem_amount(amount: u64, quantity_in_sys_decimal: u128) -> u64 {
+    let amount_return: u128 = quantity_in_sys_decimal.checked_mul(amount.into()).unwrap();
+    Calculator::restore_raw_decimal(amount_return)
+}
+
+/// Amounts of every component returned for `amount` burned basket tokens, checked against the
+/// `min_amounts_out` slippage guard given in the same order.
+pub(crate) fn calculate_redeem_amounts(
+    amount: u64,
+    components: &[BasketComponent],
+    min_amounts_out: &[u64],
+) -> Result<Vec<u64>> {
+    require!(
+        min_amounts_out.len() == components.len(),
+        PieError::InvalidMinAmountsOut
+    );
+
+    components
+        .iter()
+        .zip(min_amounts_out)
+        .map(|(component, min_amount_out)| {
+            let amount_return = calculate_redeem_amount(amount, component.quantity_in_sys_decimal);
+            require!(
+                amount_return >= *min_amount_out,
+                PieError::InsufficientRedeemAmount
+            );
+            Ok(amount_return)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_redeem_amounts() {
+        let components = [
+            BasketComponent { mint: Pubkey::new_unique(), quantity_in_sys_decimal: 2_000_000, decimals: 6, target_weight_bp: 0 },
+            BasketComponent { mint: Pubkey::new_unique(), quantity_in_sys_decimal: 1_500_000, decimals: 6, target_weight_bp: 0 },
+        ];
+
+        assert_eq!(calculate_redeem_amounts(10, &components, &[0, 0]).unwrap(), vec![20, 15]);
+        assert_eq!(calculate_redeem_amounts(10, &components, &[20, 15]).unwrap(), vec![20, 15]);
+        assert_eq!(
+            calculate_redeem_amounts(10, &components, &[20, 16]),
+            err!(PieError::InsufficientRedeemAmount)
+        );
+        assert_eq!(
+            calculate_redeem_amounts(10, &components, &[20]),
+            err!(PieError::InvalidMinAmountsOut)
+        );
+    }
+}
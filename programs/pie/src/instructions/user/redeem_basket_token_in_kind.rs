@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{burn, Burn, Token, TokenAccount},
+    token_interface::{Mint, Token2022},
+};
+
+use crate::{
+    constant::{BASKET_CONFIG, PAUSE_REDEEM, PROGRAM_STATE, USER_MINT_RECORD},
+    error::PieError,
+    states::{BasketState, UserMintRecord},
+    utils::{
+        transfer_checked_from_pool_vault_to_user, validate_in_kind_component_accounts, Calculator,
+    },
+    BasketConfig, ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct RedeemBasketTokenInKindContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = basket_mint,
+        token::authority = user,
+    )]
+    pub user_basket_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// Mint record of the user, decreased by the redeemed amount when passed
+    #[account(
+        mut,
+        seeds = [USER_MINT_RECORD, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump = user_mint_record.bump
+    )]
+    pub user_mint_record: Option<Account<'info, UserMintRecord>>,
+    // remaining accounts, for each component in `basket_config.components` order:
+    // component mint
+    // user token account
+    // vault token account (ATA of basket_config)
+}
+
+#[event]
+pub struct RedeemBasketTokenInKindEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+    pub basket_mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Burns `amount` basket tokens and transfers each component's pro-rata share
+/// from the vault straight to the user's token accounts.
+pub fn redeem_basket_token_in_kind<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RedeemBasketTokenInKindContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, PieError::InvalidAmount);
+
+    let basket_config = &ctx.accounts.basket_config;
+    require!(
+        basket_config.state != BasketState::Rebalancing,
+        PieError::RebalancingInProgress
+    );
+    basket_config.check_not_paused(&ctx.accounts.program_state, PAUSE_REDEEM)?;
+    require!(
+        ctx.accounts.user_basket_token_account.amount >= amount,
+        PieError::InsufficientBalance
+    );
+    require!(
+        ctx.remaining_accounts.len() == basket_config.components.len() * 3,
+        PieError::InvalidRemainingAccounts
+    );
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.basket_mint.to_account_info(),
+                from: ctx.accounts.user_basket_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    if let Some(user_mint_record) = ctx.accounts.user_mint_record.as_mut() {
+        user_mint_record.record_redeem(amount);
+    }
+
+    let signer: &[&[&[u8]]] = &[&[
+        BASKET_CONFIG,
+        &basket_config.id.to_be_bytes(),
+        &[basket_config.bump],
+    ]];
+
+    for (component, accounts) in basket_config
+        .components
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(3))
+    {
+        let (mint, user_token_account, vault_token_account) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        let (decimals, token_program) = validate_in_kind_component_accounts(
+            &basket_config.key(),
+            &ctx.accounts.user.key(),
+            &component.mint,
+            mint,
+            user_token_account,
+            vault_token_account,
+        )?;
+
+        let token_program = if token_program == ctx.accounts.token_program.key() {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.accounts.token_program_2022.to_account_info()
+        };
+
+        // Same rounding as `redeem_basket_token`: components are always rounded down
+        let amount_return = Calculator::restore_raw_decimal(
+            component
+                .quantity_in_sys_decimal
+                .checked_mul(amount.into())
+                .unwrap(),
+        );
+
+        transfer_checked_from_pool_vault_to_user(
+            vault_token_account,
+            user_token_account,
+            mint,
+            &basket_config.to_account_info(),
+            &token_program,
+            amount_return,
+            decimals,
+            signer,
+        )?;
+    }
+
+    emit!(RedeemBasketTokenInKindEvent {
+        basket_id: basket_config.id,
+        user: ctx.accounts.user.key(),
+        basket_mint: ctx.accounts.basket_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::{self, burn, close_account, Burn, CloseAccount, Token},
+    token_2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    constant::{
+        BASKET_CONFIG, NATIVE_MINT, PAUSE_REDEEM, PAUSE_SWAP, PROGRAM_STATE, USER_BALANCE,
+        USER_MINT_RECORD,
+    },
+    error::PieError,
+    instructions::JupiterSwapRoute,
+    states::{BasketState, UserBalance, UserMintRecord},
+    utils::{
+        calculate_fee_amount, execute_jupiter_swap, transfer_from_pool_vault_to_user, Calculator,
+        SwapBounds,
+    },
+    BasketConfig, ProgramState,
+};
+
+#[derive(Accounts)]
+pub struct RedeemBasketTokenToSolContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        seeds = [BASKET_CONFIG, &basket_config.id.to_be_bytes()],
+        bump = basket_config.bump
+    )]
+    pub basket_config: Box<Account<'info, BasketConfig>>,
+
+    #[account(
+        mut,
+        address = basket_config.mint
+    )]
+    pub basket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = basket_mint,
+        token::authority = user,
+    )]
+    pub user_basket_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [USER_BALANCE, &user.key().as_ref()],
+        bump = user_balance.bump,
+    )]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+
+    #[account(
+        mut,
+        token::mint = NATIVE_MINT,
+        token::authority = user
+    )]
+    pub user_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = NATIVE_MINT,
+        associated_token::authority = basket_config
+    )]
+    pub vault_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Platform fee wallet that receives the fee
+    #[account(mut, address = program_state.platform_fee_wallet)]
+    pub platform_fee_wallet: AccountInfo<'info>,
+
+    /// CHECK: Creator fee wallet that receives the fee
+    #[account(mut, address = basket_config.creator)]
+    pub creator_fee_wallet: AccountInfo<'info>,
+
+    /// CHECK: Jupiter program will be checked in require
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// Mint record of the user, decreased by the redeemed amount when passed
+    #[account(
+        mut,
+        seeds = [USER_MINT_RECORD, &basket_config.id.to_be_bytes(), user.key().as_ref()],
+        bump = user_mint_record.bump
+    )]
+    pub user_mint_record: Option<Account<'info, UserMintRecord>>,
+    // remaining accounts, for each route:
+    // vault token account of the component sold by the route
+    // `accounts_len` Jupiter accounts
+}
+
+#[event]
+pub struct RedeemBasketTokenToSolEvent {
+    pub basket_id: u64,
+    pub user: Pubkey,
+    pub basket_mint: Pubkey,
+    pub amount: u64,
+    pub amount_received: u64,
+    pub creator_fee: u64,
+    pub platform_fee: u64,
+}
+
+/// Burns `amount` basket tokens, sells every component share to WSOL with the given
+/// Jupiter routes and unwraps the proceeds to the user in a single instruction.
+/// Routes follow `basket_config.components` order and skip components with nothing to sell,
+/// i.e. whose share of `amount` rounds down to zero.
+/// Component amounts left unsold by a route are recorded in `UserBalance`.
+pub fn redeem_basket_token_to_sol<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RedeemBasketTokenToSolContext<'info>>,
+    amount: u64,
+    routes: Vec<JupiterSwapRoute>,
+    min_sol_out: u64,
+) -> Result<()> {
+    require!(amount > 0, PieError::InvalidAmount);
+    require!(
+        ctx.accounts.basket_config.state != BasketState::Rebalancing,
+        PieError::RebalancingInProgress
+    );
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_REDEEM | PAUSE_SWAP)?;
+    require!(
+        ctx.accounts.user_basket_token_account.amount >= amount,
+        PieError::InsufficientBalance
+    );
+
+    // Same rounding as `redeem_basket_token`: components are always rounded down
+    let mut sales = Vec::with_capacity(ctx.accounts.basket_config.components.len());
+    for component in ctx.accounts.basket_config.components.iter() {
+        let amount_to_sell = Calculator::restore_raw_decimal(
+            component
+                .quantity_in_sys_decimal
+                .checked_mul(amount.into())
+                .ok_or(PieError::InvalidQuantity)?,
+        );
+        if amount_to_sell > 0 {
+            sales.push((component.mint, amount_to_sell));
+        }
+    }
+    require!(
+        !sales.is_empty() && routes.len() == sales.len(),
+        PieError::InvalidRemainingAccounts
+    );
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.basket_mint.to_account_info(),
+                from: ctx.accounts.user_basket_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    if let Some(user_mint_record) = ctx.accounts.user_mint_record.as_mut() {
+        user_mint_record.record_redeem(amount);
+    }
+
+    let basket_id = ctx.accounts.basket_config.id;
+    let basket_config_key = ctx.accounts.basket_config.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BASKET_CONFIG,
+        &ctx.accounts.basket_config.id.to_be_bytes(),
+        &[ctx.accounts.basket_config.bump],
+    ]];
+
+    let mut total_amount_received: u64 = 0;
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    for ((mint, amount_to_sell), route) in sales.into_iter().zip(routes) {
+        let accounts_len = route.accounts_len as usize;
+        require!(
+            remaining_accounts.len() > accounts_len,
+            PieError::InvalidRemainingAccounts
+        );
+        let (vault_token_source, rest) = remaining_accounts.split_first().unwrap();
+        let (jupiter_accounts, rest) = rest.split_at(accounts_len);
+        remaining_accounts = rest;
+
+        let token_program = *vault_token_source.owner;
+        require!(
+            token_program == token::ID || token_program == token_2022::ID,
+            PieError::InvalidTokenProgram
+        );
+        require!(
+            *vault_token_source.key
+                == get_associated_token_address_with_program_id(
+                    &basket_config_key,
+                    &mint,
+                    &token_program,
+                ),
+            PieError::InvalidComponent
+        );
+
+        // Routes can only sell the share redeemed in this instruction
+        let outcome = execute_jupiter_swap(
+            &ctx.accounts.basket_config,
+            &ctx.accounts.jupiter_program,
+            jupiter_accounts,
+            vault_token_source,
+            &ctx.accounts.vault_wsol_account.to_account_info(),
+            route.data,
+            SwapBounds {
+                min_amount_out: None,
+                max_amount_in: Some(amount_to_sell),
+            },
+        )?;
+
+        let amount_left = amount_to_sell - outcome.amount_in;
+        if amount_left > 0 {
+            ctx.accounts
+                .user_balance
+                .upsert_balance(basket_id, mint, amount_left)?;
+        }
+
+        total_amount_received = total_amount_received
+            .checked_add(outcome.amount_out)
+            .ok_or(PieError::ConversionFailure)?;
+    }
+
+    ctx.accounts.user_balance.realloc_to_fit(
+        ctx.accounts.user_balance.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+    )?;
+
+    let (platform_fee_amount, creator_fee_amount) = calculate_fee_amount(
+        ctx.accounts.program_state.platform_fee_bp,
+        ctx.accounts.basket_config.creator_fee_bp,
+        total_amount_received,
+    )?;
+    let amount_out = total_amount_received
+        .checked_sub(platform_fee_amount)
+        .unwrap()
+        .checked_sub(creator_fee_amount)
+        .ok_or(PieError::InvalidFee)?;
+    require!(amount_out >= min_sol_out, PieError::InsufficientSolAmount);
+
+    // Transfer WSOL to user first
+    transfer_from_pool_vault_to_user(
+        &ctx.accounts.vault_wsol_account.to_account_info(),
+        &ctx.accounts.user_wsol_account.to_account_info(),
+        &ctx.accounts.basket_config.to_account_info(),
+        &ctx.accounts.token_program,
+        total_amount_received,
+        signer_seeds,
+    )?;
+
+    // Close token account to recover SOL
+    close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.user_wsol_account.to_account_info(),
+            destination: ctx.accounts.user.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+    ))?;
+
+    // Transfer SOL to fee destinations
+    for (fee_wallet, fee_amount) in [
+        (
+            ctx.accounts.platform_fee_wallet.to_account_info(),
+            platform_fee_amount,
+        ),
+        (
+            ctx.accounts.creator_fee_wallet.to_account_info(),
+            creator_fee_amount,
+        ),
+    ] {
+        if fee_amount > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: fee_wallet,
+                    },
+                ),
+                fee_amount,
+            )?;
+        }
+    }
+
+    emit!(RedeemBasketTokenToSolEvent {
+        basket_id,
+        user: ctx.accounts.user.key(),
+        basket_mint: ctx.accounts.basket_mint.key(),
+        amount,
+        amount_received: total_amount_received,
+        creator_fee: creator_fee_amount,
+        platform_fee: platform_fee_amount,
+    });
+
+    Ok(())
+}
@@ -1,13 +1,10 @@
 use crate::{
-    constant::{JUPITER_PROGRAM_ID, USER_FUND},
-    error::PieError,
-    utils::calculate_amounts_swapped_and_received,
-    BasketConfig, UserFund, BASKET_CONFIG, NATIVE_MINT,
-};
-use anchor_lang::{
-    prelude::*,
-    solana_program::{instruction::Instruction, program::invoke_signed},
+    constant::USER_FUND,
+    instructions::sell_component_with_swap,
+    utils::{JupiterSwap, SwapRequest},
+    BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE,
 };
+use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
@@ -29,6 +26,12 @@ pub struct SellComponentJupiterContext<'info> {
     )]
     pub basket_config: Box<Account<'info, BasketConfig>>,
 
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
     #[account(
         mut,
         associated_token::authority = basket_config,
@@ -61,91 +64,29 @@ pub struct SellComponentJupiterEvent {
     pub amount_received: u64,
 }
 
-pub fn sell_component_jupiter(
-    ctx: Context<SellComponentJupiterContext>,
+/// Sells a component using Jupiter for WSOL, see `sell_component`
+pub fn sell_component_jupiter<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SellComponentJupiterContext<'info>>,
     data: Vec<u8>,
 ) -> Result<()> {
-    require!(
-        ctx.accounts
-            .basket_config
-            .components
-            .iter()
-            .any(|c| c.mint == ctx.accounts.vault_token_source.mint.key()),
-        PieError::InvalidComponent
-    );
-    require!(
-        ctx.accounts.jupiter_program.key() == JUPITER_PROGRAM_ID,
-        PieError::InvalidJupiterProgram
-    );
-
-    let user_fund = &mut ctx.accounts.user_fund;
-
-    let balance_in_before = ctx.accounts.vault_token_source.amount;
-    let balance_out_before = ctx.accounts.vault_token_destination.amount;
-
-    // Prepare accounts for jupiter program
-    let accounts: Vec<AccountMeta> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| {
-            let is_signer = acc.key == &ctx.accounts.basket_config.key();
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer,
-                is_writable: acc.is_writable,
-            }
-        })
-        .collect();
-
-    let accounts_infos: Vec<AccountInfo> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .collect();
-
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        BASKET_CONFIG,
-        &ctx.accounts.basket_config.id.to_be_bytes(),
-        &[ctx.accounts.basket_config.bump],
-    ]];
-
-    // Invoke jupiter program
-    invoke_signed(
-        &Instruction {
-            program_id: ctx.accounts.jupiter_program.key(),
-            accounts,
-            data,
-        },
-        &accounts_infos,
-        signer_seeds,
-    )?;
-
-    ctx.accounts.vault_token_source.reload()?;
-    ctx.accounts.vault_token_destination.reload()?;
-
-    let (amount_swapped, amount_received) = calculate_amounts_swapped_and_received(
-        &ctx.accounts.vault_token_source,
-        &ctx.accounts.vault_token_destination,
-        balance_in_before,
-        balance_out_before,
+    let accounts = ctx.accounts;
+    let outcome = sell_component_with_swap(
+        &mut accounts.user_fund,
+        &accounts.basket_config,
+        &accounts.program_state,
+        &accounts.vault_token_source,
+        &accounts.vault_token_destination,
+        &accounts.jupiter_program,
+        ctx.remaining_accounts,
+        &SwapRequest::Jupiter(JupiterSwap { data }),
     )?;
 
-    require!(amount_swapped > 0, PieError::InvalidSwapResult);
-    require!(amount_received > 0, PieError::InvalidSwapResult);
-
-    // Remove input token from user fund
-    // It will throw error if amount_swapped is greater than the balance of the user fund
-    user_fund.remove_component(ctx.accounts.vault_token_source.mint.key(), amount_swapped)?;
-
-    // Add output token to user fund
-    user_fund.upsert_component(NATIVE_MINT, amount_received)?;
-
     emit!(SellComponentJupiterEvent {
-        basket_id: ctx.accounts.basket_config.id,
-        user: ctx.accounts.user.key(),
-        mint: ctx.accounts.vault_token_source.mint.key(),
-        amount_swapped,
-        amount_received,
+        basket_id: accounts.basket_config.id,
+        user: accounts.user.key(),
+        mint: accounts.vault_token_source.mint,
+        amount_swapped: outcome.amount_in,
+        amount_received: outcome.amount_out,
     });
 
     Ok(())
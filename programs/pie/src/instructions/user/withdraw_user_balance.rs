@@ -1,13 +1,11 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::{instruction::Instruction, program::invoke_signed},
-};
+use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    constant::{BASKET_CONFIG, JUPITER_PROGRAM_ID, NATIVE_MINT, USER_BALANCE},
+    constant::{BASKET_CONFIG, NATIVE_MINT, PAUSE_REDEEM, PAUSE_SWAP, PROGRAM_STATE, USER_BALANCE},
     error::PieError,
-    states::BasketConfig,
+    states::{BasketConfig, ProgramState},
+    utils::{execute_jupiter_swap, SwapBounds},
     UserBalance,
 };
 
@@ -33,6 +31,12 @@ pub struct WithdrawUserBalanceContext<'info> {
     )]
     pub basket_config: Account<'info, BasketConfig>,
 
+    #[account(
+        seeds = [PROGRAM_STATE],
+        bump = program_state.bump
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
     #[account(
         address = vault_token_source.mint
     )]
@@ -63,23 +67,16 @@ pub struct WithdrawUserBalanceContext<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn withdraw_user_balance(
-    ctx: Context<WithdrawUserBalanceContext>,
+pub fn withdraw_user_balance<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, WithdrawUserBalanceContext<'info>>,
     data: Vec<u8>,
 ) -> Result<()> {
-    require!(
-        ctx.accounts.jupiter_program.key() == JUPITER_PROGRAM_ID,
-        PieError::InvalidJupiterProgram
-    );
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_REDEEM | PAUSE_SWAP)?;
 
-    let user = &mut ctx.accounts.user;
     let user_balance = &mut ctx.accounts.user_balance;
-    let vault_token_source = &mut ctx.accounts.vault_token_source;
     let vault_token_source_mint = ctx.accounts.vault_token_source_mint.key();
-    let user_wsol_account = &mut ctx.accounts.user_wsol_account;
-
-    let vault_balance_before = vault_token_source.amount;
-    let user_wsol_balance_before = user_wsol_account.amount;
 
     // Find and update the balance for this token
     let balance_index = user_balance
@@ -90,63 +87,23 @@ pub fn withdraw_user_balance(
         })
         .ok_or(PieError::ComponentNotFound)?;
 
-    // Prepare accounts for jupiter program
-    let accounts: Vec<AccountMeta> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| {
-            let is_signer = acc.key == &ctx.accounts.basket_config.key();
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer,
-                is_writable: acc.is_writable,
-            }
-        })
-        .collect();
-
-    let accounts_infos: Vec<AccountInfo> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .collect();
-
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        BASKET_CONFIG,
-        &ctx.accounts.basket_config.id.to_be_bytes(),
-        &[ctx.accounts.basket_config.bump],
-    ]];
-
-    // Invoke jupiter program
-    invoke_signed(
-        &Instruction {
-            program_id: ctx.accounts.jupiter_program.key(),
-            accounts,
-            data,
+    let outcome = execute_jupiter_swap(
+        &ctx.accounts.basket_config,
+        &ctx.accounts.jupiter_program,
+        ctx.remaining_accounts,
+        &ctx.accounts.vault_token_source.to_account_info(),
+        &ctx.accounts.user_wsol_account.to_account_info(),
+        data,
+        SwapBounds {
+            min_amount_out: None,
+            max_amount_in: Some(user_balance.balances[balance_index].amount),
         },
-        &accounts_infos,
-        signer_seeds,
     )?;
 
-    vault_token_source.reload()?;
-    user_wsol_account.reload()?;
-
-    let vault_balance_after = vault_token_source.amount;
-    let user_wsol_balance_after = user_wsol_account.amount;
-
-    require!(
-        vault_balance_before - vault_balance_after > 0,
-        PieError::InvalidSwapResult
-    );
-
-    require!(
-        user_wsol_balance_after - user_wsol_balance_before > 0,
-        PieError::InvalidSwapResult
-    );
-
     // Update the balance
     user_balance.balances[balance_index].amount = user_balance.balances[balance_index]
         .amount
-        .checked_sub(vault_balance_before - vault_balance_after)
+        .checked_sub(outcome.amount_in)
         .ok_or(PieError::ConversionFailure)?;
 
     // Check if the balance is now empty
@@ -159,7 +116,10 @@ pub fn withdraw_user_balance(
     user_balance.balances.remove(balance_index);
 
     // Close the user balance account if it's empty
-    user_balance.close_if_empty(user_balance.to_account_info(), user.to_account_info())?;
+    user_balance.close_if_empty(
+        user_balance.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+    )?;
 
     Ok(())
 }
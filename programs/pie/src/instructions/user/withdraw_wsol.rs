@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::{token::{Token, CloseAccount}, token_interface::TokenAccount};
 
 use crate::{
-    constant::USER_FUND,
+    constant::{PAUSE_REDEEM, USER_FUND},
     error::PieError,
     utils::{calculate_fee_amount, transfer_from_pool_vault_to_user},
     BasketConfig, ProgramState, UserFund, BASKET_CONFIG, NATIVE_MINT, PROGRAM_STATE,
@@ -72,6 +72,10 @@ pub struct WithdrawWsolEvent {
 }
 
 pub fn withdraw_wsol(ctx: Context<WithdrawWsolContext>) -> Result<()> {
+    ctx.accounts
+        .basket_config
+        .check_not_paused(&ctx.accounts.program_state, PAUSE_REDEEM)?;
+
     let user_fund = &mut ctx.accounts.user_fund;
 
     let component = user_fund
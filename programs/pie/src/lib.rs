@@ -11,6 +11,7 @@ pub mod utils;
 use constant::*;
 use instructions::*;
 use states::*;
+use utils::SwapRequest;
 
 #[program]
 pub mod pie {
@@ -63,6 +64,101 @@ pub mod pie {
         Ok(())
     }
 
+    pub fn migrate_basket_v5(ctx: Context<MigrateBasketV5Context>) -> Result<()> {
+        instructions::migrate_basket_v5(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_program_state(ctx: Context<MigrateProgramStateContext>) -> Result<()> {
+        instructions::migrate_program_state(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_price_feed(
+        ctx: Context<SetPriceFeedContext>,
+        feed_id: [u8; 32],
+        max_staleness_secs: u64,
+        max_confidence_bp: u64,
+    ) -> Result<()> {
+        instructions::set_price_feed(ctx, feed_id, max_staleness_secs, max_confidence_bp)?;
+        Ok(())
+    }
+
+    pub fn set_twap_price_feed(
+        ctx: Context<SetTwapPriceFeedContext>,
+        source: PriceSourceKind,
+        twap_window_secs: u32,
+        max_staleness_secs: u64,
+    ) -> Result<()> {
+        instructions::set_twap_price_feed(ctx, source, twap_window_secs, max_staleness_secs)?;
+        Ok(())
+    }
+
+    pub fn crystallize_performance_fee<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CrystallizePerformanceFeeContext<'info>>,
+    ) -> Result<()> {
+        instructions::crystallize_performance_fee(ctx)?;
+        Ok(())
+    }
+
+    pub fn update_guardian(ctx: Context<UpdateGuardianContext>, new_guardian: Pubkey) -> Result<()> {
+        instructions::update_guardian(ctx, new_guardian)?;
+        Ok(())
+    }
+
+    pub fn pause_program(ctx: Context<PauseProgramContext>, pause_flags: u64) -> Result<()> {
+        instructions::pause_program(ctx, pause_flags)?;
+        Ok(())
+    }
+
+    pub fn unpause_program(ctx: Context<UnpauseProgramContext>, pause_flags: u64) -> Result<()> {
+        instructions::unpause_program(ctx, pause_flags)?;
+        Ok(())
+    }
+
+    pub fn pause_basket(ctx: Context<PauseBasketContext>, pause_flags: u64) -> Result<()> {
+        instructions::pause_basket(ctx, pause_flags)?;
+        Ok(())
+    }
+
+    pub fn unpause_basket(ctx: Context<UnpauseBasketContext>, pause_flags: u64) -> Result<()> {
+        instructions::unpause_basket(ctx, pause_flags)?;
+        Ok(())
+    }
+
+    pub fn set_token_registry_entry(
+        ctx: Context<SetTokenRegistryEntryContext>,
+        status: TokenStatus,
+        risk_flags: u64,
+    ) -> Result<()> {
+        instructions::set_token_registry_entry(ctx, status, risk_flags)?;
+        Ok(())
+    }
+
+    pub fn update_creator_whitelist(
+        ctx: Context<UpdateCreatorWhitelistContext>,
+        creator_whitelist_enabled: bool,
+    ) -> Result<()> {
+        instructions::update_creator_whitelist(ctx, creator_whitelist_enabled)?;
+        Ok(())
+    }
+
+    pub fn add_whitelisted_creator(
+        ctx: Context<AddWhitelistedCreatorContext>,
+        creator: Pubkey,
+    ) -> Result<()> {
+        instructions::add_whitelisted_creator(ctx, creator)?;
+        Ok(())
+    }
+
+    pub fn remove_whitelisted_creator(
+        ctx: Context<RemoveWhitelistedCreatorContext>,
+        creator: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_whitelisted_creator(ctx, creator)?;
+        Ok(())
+    }
+
     ///////////////////////////////
     //          Creator          //
     ///////////////////////////////
@@ -89,10 +185,67 @@ pub mod pie {
         Ok(())
     }
 
+    pub fn accrue_management_fee(ctx: Context<AccrueManagementFeeContext>) -> Result<()> {
+        instructions::accrue_management_fee(ctx)?;
+        Ok(())
+    }
+
+    pub fn update_target_weights(
+        ctx: Context<UpdateTargetWeightsContext>,
+        target_weights_bp: Vec<u64>,
+        weight_tolerance_bp: u64,
+    ) -> Result<()> {
+        instructions::update_target_weights(ctx, target_weights_bp, weight_tolerance_bp)?;
+        Ok(())
+    }
+
+    pub fn update_rebalance_limits(
+        ctx: Context<UpdateRebalanceLimitsContext>,
+        max_rebalance_slippage_bp: u64,
+        max_rebalance_loss_bp: u64,
+    ) -> Result<()> {
+        instructions::update_rebalance_limits(
+            ctx,
+            max_rebalance_slippage_bp,
+            max_rebalance_loss_bp,
+        )?;
+        Ok(())
+    }
+
+    pub fn update_mint_limits(
+        ctx: Context<UpdateMintLimitsContext>,
+        max_supply: u64,
+        max_holding_per_wallet: u64,
+    ) -> Result<()> {
+        instructions::update_mint_limits(ctx, max_supply, max_holding_per_wallet)?;
+        Ok(())
+    }
+
+    pub fn update_allowlist(
+        ctx: Context<UpdateAllowlistContext>,
+        allowlist_mode: AllowlistMode,
+        allowlist_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::update_allowlist(ctx, allowlist_mode, allowlist_root)?;
+        Ok(())
+    }
+
+    pub fn add_allowlist_entry(ctx: Context<AddAllowlistEntryContext>, user: Pubkey) -> Result<()> {
+        instructions::add_allowlist_entry(ctx, user)?;
+        Ok(())
+    }
+
+    pub fn remove_allowlist_entry(ctx: Context<RemoveAllowlistEntryContext>) -> Result<()> {
+        instructions::remove_allowlist_entry(ctx)?;
+        Ok(())
+    }
+
     ///////////////////////////////
     //        Rebalancer         //
     ///////////////////////////////
-    pub fn start_rebalancing(ctx: Context<StartRebalancing>) -> Result<()> {
+    pub fn start_rebalancing<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, StartRebalancing<'info>>,
+    ) -> Result<()> {
         instructions::start_rebalancing(ctx)?;
         Ok(())
     }
@@ -105,7 +258,17 @@ pub mod pie {
         Ok(())
     }
 
-    pub fn stop_rebalancing(ctx: Context<StopRebalancing>) -> Result<()> {
+    pub fn execute_rebalancing<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ExecuteRebalancing<'info>>,
+        request: SwapRequest,
+    ) -> Result<()> {
+        instructions::execute_rebalancing(ctx, request)?;
+        Ok(())
+    }
+
+    pub fn stop_rebalancing<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, StopRebalancing<'info>>,
+    ) -> Result<()> {
         instructions::stop_rebalancing(ctx)?;
         Ok(())
     }
@@ -123,154 +286,135 @@ pub mod pie {
         Ok(())
     }
 
-    pub fn buy_component_jupiter(
-        ctx: Context<BuyComponentJupiterContext>,
+    pub fn buy_component<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, BuyComponentContext<'info>>,
+        request: SwapRequest,
+    ) -> Result<()> {
+        instructions::buy_component(ctx, request)?;
+        Ok(())
+    }
+
+    pub fn buy_component_jupiter<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, BuyComponentJupiterContext<'info>>,
         data: Vec<u8>,
     ) -> Result<()> {
         instructions::buy_component_jupiter(ctx, data)?;
         Ok(())
     }
 
-    pub fn mint_basket_token(ctx: Context<MintBasketTokenContext>) -> Result<()> {
-        instructions::mint_basket_token(ctx)?;
+    pub fn mint_basket_token(
+        ctx: Context<MintBasketTokenContext>,
+        min_mint_amount: u64,
+    ) -> Result<()> {
+        instructions::mint_basket_token(ctx, min_mint_amount)?;
         Ok(())
     }
 
-    pub fn redeem_basket_token(ctx: Context<RedeemBasketTokenContext>, amount: u64) -> Result<()> {
-        instructions::redeem_basket_token(ctx, amount)?;
+    pub fn mint_basket_token_in_kind<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, MintBasketTokenInKindContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_basket_token_in_kind(ctx, amount)?;
         Ok(())
     }
 
-    pub fn sell_component_jupiter(
-        ctx: Context<SellComponentJupiterContext>,
-        data: Vec<u8>,
+    pub fn mint_basket_token_from_sol<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, MintBasketTokenFromSolContext<'info>>,
+        amount: u64,
+        routes: Vec<JupiterSwapRoute>,
+        min_basket_tokens_out: u64,
     ) -> Result<()> {
-        instructions::sell_component_jupiter(ctx, data)?;
+        instructions::mint_basket_token_from_sol(ctx, amount, routes, min_basket_tokens_out)?;
         Ok(())
     }
 
-    pub fn withdraw_wsol(ctx: Context<WithdrawWsolContext>) -> Result<()> {
-        instructions::withdraw_wsol(ctx)?;
+    pub fn redeem_basket_token(
+        ctx: Context<RedeemBasketTokenContext>,
+        amount: u64,
+        min_amounts_out: Vec<u64>,
+    ) -> Result<()> {
+        instructions::redeem_basket_token(ctx, amount, min_amounts_out)?;
         Ok(())
     }
 
-    pub fn withdraw_user_balance(
-        ctx: Context<WithdrawUserBalanceContext>,
-        data: Vec<u8>,
+    pub fn redeem_basket_token_in_kind<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RedeemBasketTokenInKindContext<'info>>,
+        amount: u64,
     ) -> Result<()> {
-        instructions::withdraw_user_balance(ctx, data)?;
+        instructions::redeem_basket_token_in_kind(ctx, amount)?;
         Ok(())
     }
 
-    ///////////////////////////////
-    //        Deprecated         //
-    ///////////////////////////////
-
-    pub fn execute_rebalancing<'a, 'b, 'c: 'info, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, ExecuteRebalancing<'info>>,
-        is_swap_base_out: bool,
-        amount_in: u64,
-        amount_out: u64,
+    pub fn redeem_basket_token_to_sol<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RedeemBasketTokenToSolContext<'info>>,
+        amount: u64,
+        routes: Vec<JupiterSwapRoute>,
+        min_sol_out: u64,
     ) -> Result<()> {
-        instructions::execute_rebalancing(ctx, is_swap_base_out, amount_in, amount_out)?;
+        instructions::redeem_basket_token_to_sol(ctx, amount, routes, min_sol_out)?;
         Ok(())
     }
 
-    pub fn execute_rebalancing_cpmm<'a, 'b, 'c: 'info, 'info>(
-        ctx: Context<ExecuteRebalancingCpmm>,
-        is_swap_base_out: bool,
-        amount_in: u64,
-        amount_out: u64,
+    pub fn sell_component<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SellComponentContext<'info>>,
+        request: SwapRequest,
     ) -> Result<()> {
-        instructions::execute_rebalancing_cpmm(ctx, is_swap_base_out, amount_in, amount_out)?;
+        instructions::sell_component(ctx, request)?;
         Ok(())
     }
 
-    pub fn execute_rebalancing_clmm<'a, 'b, 'c: 'info, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, ExecuteRebalancingClmm<'info>>,
-        is_swap_base_out: bool,
-        amount: u64,
-        other_amount_threshold: u64,
-        sqrt_price_limit_x64: u128,
+    pub fn sell_component_jupiter<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SellComponentJupiterContext<'info>>,
+        data: Vec<u8>,
     ) -> Result<()> {
-        instructions::execute_rebalancing_clmm(
-            ctx,
-            is_swap_base_out,
-            amount,
-            other_amount_threshold,
-            sqrt_price_limit_x64,
-        )?;
+        instructions::sell_component_jupiter(ctx, data)?;
         Ok(())
     }
 
-    pub fn buy_component(
-        ctx: Context<BuyComponentContext>,
-        max_amount_in: u64,
-        amount_out: u64,
-    ) -> Result<()> {
-        instructions::buy_component(ctx, max_amount_in, amount_out)?;
+    pub fn withdraw_wsol(ctx: Context<WithdrawWsolContext>) -> Result<()> {
+        instructions::withdraw_wsol(ctx)?;
         Ok(())
     }
 
-    pub fn buy_component_cpmm(
-        ctx: Context<BuyComponentCpmm>,
-        max_amount_in: u64,
-        amount_out: u64,
+    pub fn withdraw_user_balance<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, WithdrawUserBalanceContext<'info>>,
+        data: Vec<u8>,
     ) -> Result<()> {
-        instructions::buy_component_cpmm(ctx, max_amount_in, amount_out)?;
+        instructions::withdraw_user_balance(ctx, data)?;
         Ok(())
     }
 
-    pub fn buy_component_clmm<'a, 'b, 'c: 'info, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, BuyComponentClmm<'info>>,
-        amount: u64,
-        other_amount_threshold: u64,
-        sqrt_price_limit_x64: u128,
+    pub fn quote_nav<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, QuoteNavContext<'info>>,
     ) -> Result<()> {
-        instructions::buy_component_clmm(
-            ctx,
-            amount,
-            other_amount_threshold,
-            sqrt_price_limit_x64,
-        )?;
+        instructions::quote_nav(ctx)?;
         Ok(())
     }
 
-    pub fn deposit_component(ctx: Context<DepositComponent>, amount: u64) -> Result<()> {
-        instructions::deposit_component(ctx, amount)?;
+    pub fn quote_mint(ctx: Context<QuoteMintContext>) -> Result<()> {
+        instructions::quote_mint(ctx)?;
         Ok(())
     }
 
-    pub fn sell_component(
-        ctx: Context<SellComponentContext>,
-        amount_in: u64,
-        minimum_amount_out: u64,
-    ) -> Result<()> {
-        instructions::sell_component(ctx, amount_in, minimum_amount_out)?;
+    pub fn quote_redeem(ctx: Context<QuoteRedeemContext>, amount: u64) -> Result<()> {
+        instructions::quote_redeem(ctx, amount)?;
         Ok(())
     }
 
-    pub fn sell_component_cpmm(
-        ctx: Context<SellComponentCpmm>,
-        amount_in: u64,
-        minimum_amount_out: u64,
+    pub fn claim_allowlist_entry(
+        ctx: Context<ClaimAllowlistEntryContext>,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        instructions::sell_component_cpmm(ctx, amount_in, minimum_amount_out)?;
+        instructions::claim_allowlist_entry(ctx, proof)?;
         Ok(())
     }
 
-    pub fn sell_component_clmm<'a, 'b, 'c: 'info, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, SellComponentClmm<'info>>,
-        amount: u64,
-        other_amount_threshold: u64,
-        sqrt_price_limit_x64: u128,
-    ) -> Result<()> {
-        instructions::sell_component_clmm(
-            ctx,
-            amount,
-            other_amount_threshold,
-            sqrt_price_limit_x64,
-        )?;
+    ///////////////////////////////
+    //        Deprecated         //
+    ///////////////////////////////
+
+    pub fn deposit_component(ctx: Context<DepositComponent>, amount: u64) -> Result<()> {
+        instructions::deposit_component(ctx, amount)?;
         Ok(())
     }
 
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Approval of `user` to deposit into and mint an allowlisted basket.
+#[account]
+pub struct AllowlistEntry {
+    pub bump: u8,
+    pub basket_id: u64,
+    pub user: Pubkey,
+    /// Account that paid the rent of the entry, refunded when it is removed
+    pub payer: Pubkey,
+    pub reserved: [u64; 4],
+}
+
+impl Space for AllowlistEntry {
+    const INIT_SPACE: usize = 8 // Account discriminator added by Anchor for each account
+        + 1  // bump (u8)
+        + 8  // basket_id (u64)
+        + 32 // user (Pubkey)
+        + 32 // payer (Pubkey)
+        + 8 * 4; // reserved
+}
@@ -1,10 +1,17 @@
-use crate::{constant::MAX_COMPONENTS, error::PieError, utils::Calculator};
+use crate::{
+    constant::{BASIS_POINTS, MAX_COMPONENTS},
+    error::PieError,
+    states::{AllowlistEntry, ProgramState, TokenRegistryEntry},
+    utils::Calculator,
+};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct BasketComponent {
     pub mint: Pubkey,
     pub quantity_in_sys_decimal: u128,
+    pub decimals: u8,
+    pub target_weight_bp: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -21,8 +28,15 @@ pub enum BasketState {
     Inactive,    // Redeem and sell are allowed
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AllowlistMode {
+    None,   // Anyone can deposit and mint
+    Pda,    // Only users with an allowlist entry added by the creator
+    Merkle, // Only users with an allowlist entry claimed with a proof against `allowlist_root`
+}
+
 #[account]
-// @dev: V2
+// @dev: V5
 pub struct BasketConfig {
     pub bump: u8,
     pub id: u64,
@@ -34,7 +48,29 @@ pub struct BasketConfig {
     pub rebalance_type: RebalanceType,
     pub creator_fee_bp: u64,
     pub components: Vec<BasketComponent>,
-    pub reserved: [u64; 10],
+    pub management_fee_bp: u64,
+    pub last_management_fee_accrual_ts: i64,
+    pub performance_fee_bp: u64,
+    pub high_water_mark: u64,
+    pub weight_tolerance_bp: u64,
+    pub max_rebalance_slippage_bp: u64,
+    pub max_rebalance_loss_bp: u64,
+    /// Basket value when the rebalancing session started, in quote units scaled by 10^6
+    pub rebalance_start_value: u64,
+    /// Loss accumulated by the rebalancing session, in quote units scaled by 10^6
+    pub rebalance_loss_value: u64,
+    /// Maximum basket token supply, 0 when uncapped
+    pub max_supply: u64,
+    /// Maximum basket tokens a user can have minted net of their redemptions, tracked in
+    /// their `UserMintRecord`, 0 when uncapped
+    pub max_holding_per_wallet: u64,
+    pub allowlist_mode: AllowlistMode,
+    pub allowlist_root: [u8; 32],
+    /// Components can be added without an approved token registry entry
+    pub permissionless_components: bool,
+    /// Bitmask of `PAUSE_*` set by the guardian for this basket
+    pub pause_flags: u64,
+    pub reserved: [u8; 86],
 }
 
 impl Space for BasketConfig {
@@ -49,8 +85,23 @@ impl Space for BasketConfig {
         + 1  // rebalance_type (RebalanceType)
         + 8  // creator_fee_bp (u64)
         + 4 // vec length
-        + (32 + 16) * MAX_COMPONENTS as usize // MAX_COMPONENTS was 30 in V1, now 15
-        + 8 * 10; // reserved
+        + (32 + 16 + 1 + 8) * MAX_COMPONENTS as usize // MAX_COMPONENTS was 30 in V1, now 15; decimals added in V3, target_weight_bp in V4
+        + 8  // management_fee_bp (u64)
+        + 8  // last_management_fee_accrual_ts (i64)
+        + 8  // performance_fee_bp (u64)
+        + 8  // high_water_mark (u64)
+        + 8  // weight_tolerance_bp (u64)
+        + 8  // max_rebalance_slippage_bp (u64)
+        + 8  // max_rebalance_loss_bp (u64)
+        + 8  // rebalance_start_value (u64)
+        + 8  // rebalance_loss_value (u64)
+        + 8  // max_supply (u64)
+        + 8  // max_holding_per_wallet (u64)
+        + 1  // allowlist_mode (AllowlistMode)
+        + 32 // allowlist_root ([u8; 32])
+        + 1  // permissionless_components (bool)
+        + 8  // pause_flags (u64)
+        + 86; // reserved
 }
 
 impl BasketConfig {
@@ -61,15 +112,25 @@ impl BasketConfig {
             .find(|component| component.mint == mint)
     }
 
-    /// Adds or updates a component with the given mint and amount.
+    /// Adds or updates a component with the given mint, mint decimals and amount.
     /// Computes `quantity_in_sys_decimal` internally.
-    pub fn upsert_component(&mut self, mint: Pubkey, amount: u64, total_supply: u64) -> Result<()> {
+    /// New components need an approved `token_registry_entry` unless the basket has
+    /// `permissionless_components`.
+    pub fn upsert_component(
+        &mut self,
+        mint: Pubkey,
+        decimals: u8,
+        amount: u64,
+        total_supply: u64,
+        token_registry_entry: Option<&TokenRegistryEntry>,
+    ) -> Result<()> {
         let quantity_in_sys_decimal = Calculator::apply_sys_decimal(amount)
             .checked_div(total_supply.try_into().unwrap())
             .ok_or(PieError::InvalidQuantity)?;
 
         if let Some(component) = self.find_component_mut(mint) {
             component.quantity_in_sys_decimal = quantity_in_sys_decimal;
+            component.decimals = decimals;
         } else {
             // validate if the basket allow component change
             require!(
@@ -81,15 +142,143 @@ impl BasketConfig {
                 self.components.len() < MAX_COMPONENTS as usize,
                 PieError::MaxAssetsExceeded
             );
+            if !self.permissionless_components {
+                token_registry_entry
+                    .ok_or(PieError::TokenNotApproved)?
+                    .check_approved(&mint)?;
+            }
             self.components.push(BasketComponent {
                 mint,
                 quantity_in_sys_decimal,
+                decimals,
+                target_weight_bp: 0,
             });
         }
 
         Ok(())
     }
 
+    /// Rescales every component quantity after basket tokens were minted without
+    /// depositing components (e.g. fee mints), so that the vault keeps backing
+    /// `quantity_in_sys_decimal * new_supply`. Quantities are rounded down.
+    pub fn dilute_components(&mut self, old_supply: u64, new_supply: u64) -> Result<()> {
+        require!(new_supply >= old_supply, PieError::InvalidAmount);
+        if old_supply == new_supply || new_supply == 0 {
+            return Ok(());
+        }
+
+        for component in self.components.iter_mut() {
+            component.quantity_in_sys_decimal = component
+                .quantity_in_sys_decimal
+                .checked_mul(old_supply.into())
+                .ok_or(PieError::InvalidQuantity)?
+                .checked_div(new_supply.into())
+                .ok_or(PieError::InvalidQuantity)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the basket is rebalanced toward target weights.
+    pub fn is_weighted(&self) -> bool {
+        self.components
+            .iter()
+            .any(|component| component.target_weight_bp > 0)
+    }
+
+    /// Checks the supply cap and the per-wallet cap after a user mint.
+    /// `net_minted_after` is the `UserMintRecord` amount of the user including the mint.
+    pub fn check_mint_limits(&self, supply_after: u64, net_minted_after: u64) -> Result<()> {
+        require!(
+            self.max_supply == 0 || supply_after <= self.max_supply,
+            PieError::MaxSupplyExceeded
+        );
+        require!(
+            self.max_holding_per_wallet == 0 || net_minted_after <= self.max_holding_per_wallet,
+            PieError::MaxHoldingExceeded
+        );
+        Ok(())
+    }
+
+    /// Checks that none of the `PAUSE_*` flags in `operations` is set on the program or
+    /// on the basket.
+    pub fn check_not_paused(&self, program_state: &ProgramState, operations: u64) -> Result<()> {
+        require!(
+            (program_state.pause_flags | self.pause_flags) & operations == 0,
+            PieError::Paused
+        );
+        Ok(())
+    }
+
+    /// Sets the allowlist mode. `allowlist_root` is the Merkle root of the allowlisted
+    /// users in `AllowlistMode::Merkle`, and must be zero otherwise.
+    pub fn set_allowlist(
+        &mut self,
+        allowlist_mode: AllowlistMode,
+        allowlist_root: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            (allowlist_mode == AllowlistMode::Merkle) == (allowlist_root != [0; 32]),
+            PieError::InvalidAllowlist
+        );
+        self.allowlist_mode = allowlist_mode;
+        self.allowlist_root = allowlist_root;
+        Ok(())
+    }
+
+    /// Checks that `user` may deposit into and mint the basket.
+    pub fn check_allowlisted(
+        &self,
+        user: &Pubkey,
+        allowlist_entry: Option<&AllowlistEntry>,
+    ) -> Result<()> {
+        if self.allowlist_mode == AllowlistMode::None {
+            return Ok(());
+        }
+        require!(
+            allowlist_entry.is_some_and(|entry| entry.basket_id == self.id && entry.user == *user),
+            PieError::NotAllowlisted
+        );
+        Ok(())
+    }
+
+    /// Returns true if rebalancing swaps are bounded by oracle prices.
+    pub fn has_rebalance_limits(&self) -> bool {
+        self.max_rebalance_slippage_bp > 0 && self.max_rebalance_loss_bp > 0
+    }
+
+    /// Sets the target weight of each component, in `components` order.
+    /// Weights are either all zero (quantity based basket) or sum up to `BASIS_POINTS`.
+    pub fn set_target_weights(
+        &mut self,
+        target_weights_bp: &[u64],
+        weight_tolerance_bp: u64,
+    ) -> Result<()> {
+        require!(
+            target_weights_bp.len() == self.components.len(),
+            PieError::InvalidTargetWeights
+        );
+        require!(
+            weight_tolerance_bp <= BASIS_POINTS,
+            PieError::InvalidTargetWeights
+        );
+        let total_weight_bp = target_weights_bp
+            .iter()
+            .try_fold(0u64, |total, weight| total.checked_add(*weight))
+            .ok_or(PieError::InvalidTargetWeights)?;
+        require!(
+            total_weight_bp == 0 || total_weight_bp == BASIS_POINTS,
+            PieError::InvalidTargetWeights
+        );
+
+        for (component, target_weight_bp) in self.components.iter_mut().zip(target_weights_bp) {
+            component.target_weight_bp = *target_weight_bp;
+        }
+        self.weight_tolerance_bp = weight_tolerance_bp;
+
+        Ok(())
+    }
+
     /// Removes a component with the given mint.
     pub fn remove_component(&mut self, mint: Pubkey) {
         // if the basket is component fixed, instead of removing the component, we set the quantity to 0
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{constant::MAX_WHITELISTED_CREATORS, error::PieError};
+
+/// Creators allowed to create baskets while `ProgramState::creator_whitelist_enabled` is set.
+#[account]
+pub struct CreatorWhitelist {
+    pub bump: u8,
+    pub creators: Vec<Pubkey>,
+}
+
+impl Space for CreatorWhitelist {
+    const INIT_SPACE: usize = 8 // Account discriminator added by Anchor for each account
+        + 1 // bump (u8)
+        + 4 // vec length
+        + 32 * MAX_WHITELISTED_CREATORS; // creators
+}
+
+impl CreatorWhitelist {
+    pub fn contains(&self, creator: &Pubkey) -> bool {
+        self.creators.contains(creator)
+    }
+
+    pub fn add_creator(&mut self, creator: Pubkey) -> Result<()> {
+        if self.contains(&creator) {
+            return Ok(());
+        }
+        require!(
+            self.creators.len() < MAX_WHITELISTED_CREATORS,
+            PieError::MaxWhitelistedCreatorsExceeded
+        );
+        self.creators.push(creator);
+        Ok(())
+    }
+
+    pub fn remove_creator(&mut self, creator: &Pubkey) -> Result<()> {
+        let index = self
+            .creators
+            .iter()
+            .position(|whitelisted| whitelisted == creator)
+            .ok_or(PieError::CreatorNotWhitelisted)?;
+        self.creators.swap_remove(index);
+        Ok(())
+    }
+}
@@ -9,3 +9,18 @@ pub use user_fund::*;
 
 pub mod user_balance;
 pub use user_balance::*;
+
+pub mod user_mint_record;
+pub use user_mint_record::*;
+
+pub mod price_feed;
+pub use price_feed::*;
+
+pub mod allowlist_entry;
+pub use allowlist_entry::*;
+
+pub mod creator_whitelist;
+pub use creator_whitelist::*;
+
+pub mod token_registry;
+pub use token_registry::*;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Where the price of a `PriceFeed` comes from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceSourceKind {
+    /// Pyth pull-oracle feed `feed_id`
+    Pyth,
+    /// TWAP of the Raydium CLMM pool `pool`, converted to USD with the Pyth feed of the
+    /// other token of the pool
+    RaydiumClmmTwap,
+    /// TWAP of the Raydium CPMM pool `pool`, converted to USD like `RaydiumClmmTwap`
+    RaydiumCpmmTwap,
+}
+
+/// Price feed registered by the admin for a component mint.
+#[account]
+pub struct PriceFeed {
+    pub bump: u8,
+    pub mint: Pubkey,
+    /// Pyth feed id, unused by TWAP sources
+    pub feed_id: [u8; 32],
+    pub max_staleness_secs: u64,
+    /// Max Pyth confidence interval, unused by TWAP sources
+    pub max_confidence_bp: u64,
+    pub source: PriceSourceKind,
+    /// Pool of TWAP sources
+    pub pool: Pubkey,
+    pub twap_window_secs: u32,
+    pub reserved: [u8; 19],
+}
+
+impl Space for PriceFeed {
+    const INIT_SPACE: usize = 8 // Account discriminator added by Anchor for each account
+        + 1  // bump (u8)
+        + 32 // mint (Pubkey)
+        + 32 // feed_id ([u8; 32])
+        + 8  // max_staleness_secs (u64)
+        + 8  // max_confidence_bp (u64)
+        + 1  // source (PriceSourceKind)
+        + 32 // pool (Pubkey)
+        + 4  // twap_window_secs (u32)
+        + 19; // reserved
+}
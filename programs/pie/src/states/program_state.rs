@@ -9,6 +9,13 @@ pub struct ProgramState {
     pub basket_creation_fee: u64,
     pub platform_fee_bp: u64,
     pub is_initialized: bool,
+    /// Only whitelisted creators can create baskets when enabled
+    pub creator_whitelist_enabled: bool,
+    /// Can pause the program and baskets, only the admin can unpause
+    pub guardian: Pubkey,
+    /// Bitmask of `PAUSE_*` applied to every basket
+    pub pause_flags: u64,
+    pub reserved: [u64; 11],
 }
 
 impl Space for ProgramState {
@@ -19,5 +26,9 @@ impl Space for ProgramState {
         + 8   // basketcounter (u64)
         + 8   // basket_creation_fee (in lamports)
         + 8   // platform_fee_bp (u64)
-        + 1; // is_initialized (bool)
+        + 1   // is_initialized (bool)
+        + 1   // creator_whitelist_enabled (bool)
+        + 32  // guardian (Pubkey)
+        + 8   // pause_flags (u64)
+        + 8 * 11; // reserved
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::error::PieError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TokenStatus {
+    Approved,  // Can be added to baskets
+    Suspended, // Cannot be added to baskets, existing components are kept
+}
+
+/// Admin-curated listing of a component mint, consulted when a basket adds the mint
+/// unless the basket opted out with `permissionless_components`.
+#[account]
+pub struct TokenRegistryEntry {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub status: TokenStatus,
+    /// Bitmask of `RISK_FLAG_*`
+    pub risk_flags: u64,
+    pub reserved: [u64; 4],
+}
+
+impl Space for TokenRegistryEntry {
+    const INIT_SPACE: usize = 8 // Account discriminator added by Anchor for each account
+        + 1  // bump (u8)
+        + 32 // mint (Pubkey)
+        + 1  // status (TokenStatus)
+        + 8  // risk_flags (u64)
+        + 8 * 4; // reserved
+}
+
+impl TokenRegistryEntry {
+    /// Checks that the entry lists `mint` as approved.
+    pub fn check_approved(&self, mint: &Pubkey) -> Result<()> {
+        require!(
+            self.mint == *mint && self.status == TokenStatus::Approved,
+            PieError::TokenNotApproved
+        );
+        Ok(())
+    }
+}
@@ -84,6 +84,39 @@ impl UserBalance {
         }
     }
 
+    /// Reallocates the account to fit its current balances.
+    /// Additional rent is transferred from `payer` when the account grows.
+    pub fn realloc_to_fit<'info>(
+        &self,
+        user_balance: AccountInfo<'info>,
+        payer: AccountInfo<'info>,
+    ) -> Result<()> {
+        let required_space = Self::size_for_len(self.balances.len());
+        let rent = Rent::get()?;
+        let new_minimum_balance = rent.minimum_balance(required_space);
+        let current_balance = user_balance.lamports();
+
+        if current_balance < new_minimum_balance {
+            let additional_rent = new_minimum_balance
+                .checked_sub(current_balance)
+                .ok_or(PieError::InvalidAmount)?;
+
+            // Transfer additional rent using system program
+            anchor_lang::solana_program::program::invoke(
+                &anchor_lang::solana_program::system_instruction::transfer(
+                    &payer.key(),
+                    &user_balance.key(),
+                    additional_rent,
+                ),
+                &[payer, user_balance.clone()],
+            )?;
+        }
+
+        user_balance.realloc(required_space, false)?;
+
+        Ok(())
+    }
+
     /// Computes the total account size for a given number of balances.
     pub fn size_for_len(len: usize) -> usize {
         8 // Account discriminator added by Anchor for each account
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::error::PieError;
+
+/// Basket tokens minted by `user`, net of the ones they redeemed, checked against the
+/// per-wallet cap of the basket. Transfers of basket tokens do not change it.
+#[account]
+pub struct UserMintRecord {
+    pub bump: u8,
+    pub basket_id: u64,
+    pub user: Pubkey,
+    pub net_minted_amount: u64,
+    pub reserved: [u64; 4],
+}
+
+impl Space for UserMintRecord {
+    const INIT_SPACE: usize = 8 // Account discriminator added by Anchor for each account
+        + 1  // bump (u8)
+        + 8  // basket_id (u64)
+        + 32 // user (Pubkey)
+        + 8  // net_minted_amount (u64)
+        + 8 * 4; // reserved
+}
+
+impl UserMintRecord {
+    /// Records `amount` basket tokens minted by `user` and returns the net minted amount.
+    /// The record may have just been created, so its identity is set on every mint.
+    pub fn record_mint(
+        &mut self,
+        bump: u8,
+        basket_id: u64,
+        user: Pubkey,
+        amount: u64,
+    ) -> Result<u64> {
+        self.bump = bump;
+        self.basket_id = basket_id;
+        self.user = user;
+        self.net_minted_amount = self
+            .net_minted_amount
+            .checked_add(amount)
+            .ok_or(PieError::MaxHoldingExceeded)?;
+        Ok(self.net_minted_amount)
+    }
+
+    /// Records `amount` basket tokens redeemed by the user. Redeeming tokens received by
+    /// transfer cannot bring the record below zero.
+    pub fn record_redeem(&mut self, amount: u64) {
+        self.net_minted_amount = self.net_minted_amount.saturating_sub(amount);
+    }
+}
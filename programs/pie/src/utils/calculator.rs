@@ -1,4 +1,4 @@
-use crate::{error::PieError, SYS_DECIMALS};
+use crate::{error::PieError, BASKET_DECIMALS, SYS_DECIMALS};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Calculator {}
@@ -24,4 +24,51 @@ impl Calculator {
         }
         Self::restore_raw_decimal(val)
     }
+
+    /// Converts `amount / 10^scale` whole component tokens per whole basket token into
+    /// `quantity_in_sys_decimal` for a component mint with `decimals`.
+    /// Fails if the quantity can not be represented exactly.
+    pub fn ui_to_quantity_in_sys_decimal(
+        amount: u64,
+        scale: u8,
+        decimals: u8,
+    ) -> Result<u128, PieError> {
+        let numerator = u128::from(amount)
+            .checked_mul(
+                10u128
+                    .checked_pow(decimals.into())
+                    .ok_or(PieError::InvalidComponentQuantity)?,
+            )
+            .and_then(|val| val.checked_mul(SYS_DECIMALS.into()))
+            .ok_or(PieError::InvalidComponentQuantity)?;
+        let denominator = 10u128
+            .checked_pow(u32::from(scale) + u32::from(BASKET_DECIMALS))
+            .ok_or(PieError::InvalidComponentQuantity)?;
+
+        if numerator % denominator != 0 {
+            return Err(PieError::InvalidComponentQuantity);
+        }
+        Ok(numerator / denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_to_quantity_in_sys_decimal() {
+        // 0.5 token with 9 decimals per basket token
+        assert_eq!(
+            Calculator::ui_to_quantity_in_sys_decimal(5, 1, 9).unwrap(),
+            500_000_000
+        );
+        // 1 token with 6 decimals per basket token
+        assert_eq!(
+            Calculator::ui_to_quantity_in_sys_decimal(1, 0, 6).unwrap(),
+            1_000_000
+        );
+        // Below the smallest representable quantity
+        assert!(Calculator::ui_to_quantity_in_sys_decimal(1, 7, 0).is_err());
+    }
 }
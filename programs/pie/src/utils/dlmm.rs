@@ -0,0 +1,140 @@
+use anchor_lang::{prelude::*, InstructionData};
+
+use crate::{
+    error::PieError,
+    utils::{check_swap_accounts, SwapAdapter},
+};
+
+/// Positions of the swap authority, source and destination token accounts in a Meteora DLMM swap
+pub const METEORA_DLMM_SWAP_POSITIONS: [usize; 3] = [10, 4, 5];
+/// Position of the optional host fee account, left empty as the DLMM program id
+pub const METEORA_DLMM_HOST_FEE_POSITION: usize = 9;
+/// Fixed accounts of a Meteora DLMM swap, the bin arrays follow
+pub const METEORA_DLMM_SWAP_ACCOUNTS_LEN: usize = 15;
+
+/// Meteora DLMM swap, see `RaydiumAmmSwap` for the amounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeteoraDlmmSwap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub is_swap_base_out: bool,
+}
+
+impl SwapAdapter for MeteoraDlmmSwap {
+    fn program_id(&self) -> Pubkey {
+        meteora_dlmm_cpi::ID
+    }
+
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()> {
+        check_swap_accounts(
+            accounts,
+            METEORA_DLMM_SWAP_ACCOUNTS_LEN,
+            METEORA_DLMM_SWAP_POSITIONS,
+            authority,
+            source,
+            destination,
+        )?;
+        // The host fee is taken from the input of the swap
+        require!(
+            *accounts[METEORA_DLMM_HOST_FEE_POSITION].key == meteora_dlmm_cpi::ID,
+            PieError::InvalidSwapAccounts
+        );
+
+        Ok(())
+    }
+
+    fn instruction_data(&self) -> Result<Vec<u8>> {
+        let data = if self.is_swap_base_out {
+            meteora_dlmm_cpi::instruction::SwapExactOut {
+                max_in_amount: self.other_amount_threshold,
+                out_amount: self.amount,
+            }
+            .data()
+        } else {
+            meteora_dlmm_cpi::instruction::Swap {
+                amount_in: self.amount,
+                min_amount_out: self.other_amount_threshold,
+            }
+            .data()
+        };
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_meteora_dlmm_instruction_data() {
+        let swap = MeteoraDlmmSwap {
+            amount: 1_000,
+            other_amount_threshold: 900,
+            is_swap_base_out: false,
+        };
+        let data = swap.instruction_data().unwrap();
+        assert_eq!(
+            data[..8],
+            meteora_dlmm_cpi::instruction::Swap::DISCRIMINATOR
+        );
+        assert_eq!(data[8..16], 1_000u64.to_le_bytes());
+        assert_eq!(data[16..24], 900u64.to_le_bytes());
+
+        let swap = MeteoraDlmmSwap {
+            is_swap_base_out: true,
+            ..swap
+        };
+        let data = swap.instruction_data().unwrap();
+        assert_eq!(
+            data[..8],
+            meteora_dlmm_cpi::instruction::SwapExactOut::DISCRIMINATOR
+        );
+        assert_eq!(data[8..16], 900u64.to_le_bytes());
+        assert_eq!(data[16..24], 1_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_meteora_dlmm_rejects_host_fee_account() {
+        let swap = MeteoraDlmmSwap {
+            amount: 1_000,
+            other_amount_threshold: 900,
+            is_swap_base_out: false,
+        };
+        let owner = Pubkey::default();
+        let mut keys: Vec<Pubkey> = (0..METEORA_DLMM_SWAP_ACCOUNTS_LEN)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        keys[METEORA_DLMM_HOST_FEE_POSITION] = meteora_dlmm_cpi::ID;
+        let mut lamports = vec![0u64; keys.len()];
+        let mut data = vec![Vec::<u8>::new(); keys.len()];
+        let mut accounts: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, true, lamports, data, &owner, false, 0)
+            })
+            .collect();
+        let [authority, source, destination] = METEORA_DLMM_SWAP_POSITIONS.map(|i| keys[i]);
+
+        assert!(swap
+            .check_accounts(&accounts, &authority, &source, &destination)
+            .is_ok());
+
+        // A host fee account would take part of the input of the swap
+        let host_fee = Pubkey::new_unique();
+        accounts[METEORA_DLMM_HOST_FEE_POSITION].key = &host_fee;
+        assert_eq!(
+            swap.check_accounts(&accounts, &authority, &source, &destination),
+            err!(PieError::InvalidSwapAccounts)
+        );
+    }
+}
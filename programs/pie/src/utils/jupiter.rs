@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::JUPITER_PROGRAM_ID,
+    error::PieError,
+    utils::{check_no_other_token_accounts, SwapAdapter},
+};
+
+/// Anchor discriminator of Jupiter's `route` instruction
+pub const JUPITER_ROUTE_DISCRIMINATOR: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+/// Anchor discriminator of Jupiter's `shared_accounts_route` instruction
+pub const JUPITER_SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR: [u8; 8] =
+    [193, 32, 155, 51, 65, 214, 156, 129];
+/// Anchor discriminator of Jupiter's `exact_out_route` instruction
+pub const JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR: [u8; 8] = [208, 51, 239, 151, 123, 43, 237, 92];
+
+/// Positions of the accounts checked by the guard in a Jupiter instruction.
+/// Optional accounts left empty are passed as the Jupiter program id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JupiterRouteLayout {
+    pub transfer_authority: usize,
+    pub source_token_account: usize,
+    pub destination_token_account: usize,
+    /// Optional account receiving the output instead of `destination_token_account`
+    pub destination_override: Option<usize>,
+    pub platform_fee_account: usize,
+    /// Number of fixed accounts before the accounts of the route plan
+    pub accounts_len: usize,
+}
+
+impl JupiterRouteLayout {
+    /// Returns the account layout of the Jupiter instruction encoded in `data`, if it is
+    /// one of the instructions the program lends its signature to.
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        let discriminator: [u8; 8] = data.get(..8)?.try_into().ok()?;
+        match discriminator {
+            JUPITER_ROUTE_DISCRIMINATOR => Some(Self {
+                transfer_authority: 1,
+                source_token_account: 2,
+                destination_token_account: 3,
+                destination_override: Some(4),
+                platform_fee_account: 6,
+                accounts_len: 9,
+            }),
+            JUPITER_SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR => Some(Self {
+                transfer_authority: 2,
+                source_token_account: 3,
+                destination_token_account: 6,
+                destination_override: None,
+                platform_fee_account: 9,
+                accounts_len: 13,
+            }),
+            JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR => Some(Self {
+                transfer_authority: 1,
+                source_token_account: 2,
+                destination_token_account: 3,
+                destination_override: Some(4),
+                platform_fee_account: 7,
+                accounts_len: 11,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Checks a Jupiter route before `authority` signs it.
+/// The route must swap from `source` to `destination` on behalf of `authority`, without a
+/// platform fee, and must not touch any other token account owned by `authority`.
+pub fn check_jupiter_route(
+    data: &[u8],
+    jupiter_accounts: &[AccountInfo],
+    authority: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+) -> Result<()> {
+    let layout = JupiterRouteLayout::from_data(data).ok_or(PieError::InvalidJupiterRoute)?;
+    require!(
+        jupiter_accounts.len() >= layout.accounts_len,
+        PieError::InvalidJupiterRoute
+    );
+
+    let key = |index: usize| jupiter_accounts[index].key;
+    require!(
+        key(layout.transfer_authority) == authority
+            && key(layout.source_token_account) == source
+            && key(layout.destination_token_account) == destination,
+        PieError::InvalidJupiterRoute
+    );
+    if let Some(index) = layout.destination_override {
+        require!(
+            *key(index) == JUPITER_PROGRAM_ID || key(index) == destination,
+            PieError::InvalidJupiterRoute
+        );
+    }
+    require!(
+        *key(layout.platform_fee_account) == JUPITER_PROGRAM_ID,
+        PieError::InvalidJupiterRoute
+    );
+
+    check_no_other_token_accounts(jupiter_accounts, authority, source, destination)
+}
+
+/// Jupiter swap, see `check_jupiter_route` for the supported instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct JupiterSwap {
+    /// Jupiter instruction data
+    pub data: Vec<u8>,
+}
+
+impl SwapAdapter for JupiterSwap {
+    fn program_id(&self) -> Pubkey {
+        JUPITER_PROGRAM_ID
+    }
+
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()> {
+        check_jupiter_route(&self.data, accounts, authority, source, destination)
+    }
+
+    fn instruction_data(&self) -> Result<Vec<u8>> {
+        Ok(self.data.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jupiter_route_layout_from_data() {
+        let mut data = JUPITER_SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0; 16]);
+        assert_eq!(
+            JupiterRouteLayout::from_data(&data).map(|layout| layout.source_token_account),
+            Some(3)
+        );
+
+        // `shared_accounts_exact_out_route` is not supported
+        assert_eq!(
+            JupiterRouteLayout::from_data(&[176, 209, 105, 168, 154, 125, 69, 62]),
+            None
+        );
+        assert_eq!(
+            JupiterRouteLayout::from_data(&JUPITER_ROUTE_DISCRIMINATOR[..7]),
+            None
+        );
+    }
+}
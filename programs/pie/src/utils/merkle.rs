@@ -0,0 +1,41 @@
+use anchor_lang::solana_program::hash::hashv;
+
+/// Verifies that `leaf` belongs to the Merkle tree of `root`. Pairs are hashed in sorted
+/// order, so proofs do not need to encode the position of each sibling.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let leaves: Vec<[u8; 32]> = (0u8..4).map(|i| hashv(&[&[i]]).to_bytes()).collect();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[2], left], &root, leaves[3]));
+        assert!(!verify_merkle_proof(&[leaves[2], left], &root, leaves[0]));
+        assert!(!verify_merkle_proof(&[], &root, leaves[0]));
+    }
+}
@@ -6,3 +6,27 @@ pub use token::*;
 
 pub mod rebalance;
 pub use rebalance::*;
+
+pub mod oracle;
+pub use oracle::*;
+
+pub mod twap;
+pub use twap::*;
+
+pub mod merkle;
+pub use merkle::*;
+
+pub mod jupiter;
+pub use jupiter::*;
+
+pub mod swap;
+pub use swap::*;
+
+pub mod raydium;
+pub use raydium::*;
+
+pub mod whirlpool;
+pub use whirlpool::*;
+
+pub mod dlmm;
+pub use dlmm::*;
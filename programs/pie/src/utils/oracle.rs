@@ -0,0 +1,500 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{BASIS_POINTS, BASKET_DECIMALS, PYTH_RECEIVER_PROGRAM_ID},
+    error::PieError,
+    states::{BasketComponent, PriceFeed, PriceSourceKind},
+    utils::{load_clmm_twap_price, load_cpmm_twap_price, Calculator},
+};
+
+/// Anchor discriminator of the Pyth receiver `PriceUpdateV2` account
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+/// `VerificationLevel::Full` borsh tag
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+/// Offset of the price message in a fully verified `PriceUpdateV2` account:
+/// discriminator (8) + write_authority (32) + verification_level (1)
+const PRICE_MESSAGE_OFFSET: usize = 8 + 32 + 1;
+/// feed_id (32) + price (8) + conf (8) + exponent (4) + publish_time (8)
+const PRICE_MESSAGE_LEN: usize = 32 + 8 + 8 + 4 + 8;
+
+/// Values returned by `value_in_quote` are scaled by 10^VALUE_DECIMALS
+pub const VALUE_DECIMALS: i32 = 12;
+/// Values stored on accounts are scaled by 10^STORED_VALUE_DECIMALS
+pub const STORED_VALUE_DECIMALS: i32 = 6;
+
+/// Price of one whole token, `price * 10^expo` quote units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: u64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// Converts a price quoted in another token into the quote of `quote_price`, the price
+    /// of one whole unit of that token.
+    pub fn convert(&self, quote_price: &OraclePrice) -> Result<OraclePrice> {
+        let mut price = u128::from(self.price) * u128::from(quote_price.price);
+        let mut conf = (u128::from(self.price) * u128::from(quote_price.conf))
+            .checked_add(u128::from(self.conf) * u128::from(quote_price.price))
+            .ok_or(PieError::InvalidPrice)?;
+        let mut expo = self.expo + quote_price.expo;
+        while price > u128::from(u64::MAX) || conf > u128::from(u64::MAX) {
+            price /= 10;
+            conf /= 10;
+            expo += 1;
+        }
+        require!(price > 0, PieError::InvalidPrice);
+
+        Ok(OraclePrice {
+            price: price as u64,
+            conf: conf as u64,
+            expo,
+            publish_time: self.publish_time.min(quote_price.publish_time),
+        })
+    }
+}
+
+/// Source of the price of a component mint.
+pub trait PriceSource {
+    /// Returns the price of one whole token, checked for freshness at `now`.
+    fn get_price(&self, now: i64) -> Result<OraclePrice>;
+}
+
+/// Pyth pull-oracle price update checked against the registered `PriceFeed`.
+pub struct PythPriceSource<'a, 'info> {
+    pub price_feed: &'a PriceFeed,
+    pub price_update: &'a AccountInfo<'info>,
+}
+
+impl PriceSource for PythPriceSource<'_, '_> {
+    fn get_price(&self, now: i64) -> Result<OraclePrice> {
+        read_pyth_price(self.price_update, self.price_feed, now)
+    }
+}
+
+/// Basket valuation, in quote units scaled by 10^VALUE_DECIMALS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BasketNav {
+    /// Value of the whole basket supply
+    pub aum: u128,
+    /// Value of one basket token (10^BASKET_DECIMALS base units)
+    pub nav_per_token: u128,
+}
+
+/// Number of accounts read to price a mint with a `source` feed, the feed included:
+/// - `Pyth`: `[price_feed, price_update]`
+/// - TWAP sources: `[price_feed, pool, observation_state, quote_price_feed, quote_price_update]`,
+///   the quote feed being the Pyth feed of the other token of the pool
+pub fn price_accounts_len(source: PriceSourceKind) -> usize {
+    match source {
+        PriceSourceKind::Pyth => 2,
+        PriceSourceKind::RaydiumClmmTwap | PriceSourceKind::RaydiumCpmmTwap => 5,
+    }
+}
+
+/// Splits the accounts pricing one mint, starting with its price feed, from the accounts
+/// following them.
+pub fn split_price_accounts<'info>(
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<(&'info [AccountInfo<'info>], &'info [AccountInfo<'info>])> {
+    require!(!accounts.is_empty(), PieError::InvalidRemainingAccounts);
+    let price_feed = Account::<PriceFeed>::try_from(&accounts[0])?;
+    let len = price_accounts_len(price_feed.source);
+    require!(accounts.len() >= len, PieError::InvalidRemainingAccounts);
+
+    Ok(accounts.split_at(len))
+}
+
+/// Loads the price of `mint` in USD from the accounts of its price feed, see
+/// `price_accounts_len`.
+pub fn load_price<'info>(
+    mint: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<OraclePrice> {
+    require!(!accounts.is_empty(), PieError::InvalidRemainingAccounts);
+    let price_feed = Account::<PriceFeed>::try_from(&accounts[0])?;
+    require!(price_feed.mint == *mint, PieError::InvalidPriceFeed);
+    require!(
+        accounts.len() == price_accounts_len(price_feed.source),
+        PieError::InvalidRemainingAccounts
+    );
+
+    match price_feed.source {
+        PriceSourceKind::Pyth => PythPriceSource {
+            price_feed: &price_feed,
+            price_update: &accounts[1],
+        }
+        .get_price(now),
+        PriceSourceKind::RaydiumClmmTwap | PriceSourceKind::RaydiumCpmmTwap => {
+            require!(
+                accounts[1].key() == price_feed.pool,
+                PieError::InvalidPriceFeed
+            );
+            let load_twap_price = if price_feed.source == PriceSourceKind::RaydiumClmmTwap {
+                load_clmm_twap_price
+            } else {
+                load_cpmm_twap_price
+            };
+            let (price, quote_mint) = load_twap_price(
+                mint,
+                &accounts[1],
+                &accounts[2],
+                price_feed.twap_window_secs,
+                price_feed.max_staleness_secs,
+                now,
+            )?;
+            price.convert(&load_quote_price(&quote_mint, &accounts[3..], now)?)
+        }
+    }
+}
+
+/// Loads the USD price of the token a TWAP is quoted in, which must have a Pyth feed.
+fn load_quote_price<'info>(
+    quote_mint: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<OraclePrice> {
+    let quote_price_feed = Account::<PriceFeed>::try_from(&accounts[0])?;
+    require!(
+        quote_price_feed.mint == *quote_mint && quote_price_feed.source == PriceSourceKind::Pyth,
+        PieError::InvalidPriceFeed
+    );
+
+    read_pyth_price(&accounts[1], &quote_price_feed, now)
+}
+
+/// Loads the price of every component from the accounts of their price feeds, passed in
+/// `components` order.
+pub fn load_component_prices<'info>(
+    components: &[BasketComponent],
+    accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<Vec<OraclePrice>> {
+    let mut prices = Vec::with_capacity(components.len());
+    let mut remaining_accounts = accounts;
+    for component in components {
+        let (price_accounts, rest) = split_price_accounts(remaining_accounts)?;
+        prices.push(load_price(&component.mint, price_accounts, now)?);
+        remaining_accounts = rest;
+    }
+    require!(
+        remaining_accounts.is_empty(),
+        PieError::InvalidRemainingAccounts
+    );
+
+    Ok(prices)
+}
+
+/// Reads the price of `price_feed` from a Pyth receiver `PriceUpdateV2` account.
+/// Only fully verified updates published within `max_staleness_secs` of `now`, with a
+/// confidence interval within `max_confidence_bp` of the price, are accepted.
+pub fn read_pyth_price(
+    price_update: &AccountInfo,
+    price_feed: &PriceFeed,
+    now: i64,
+) -> Result<OraclePrice> {
+    require!(
+        *price_update.owner == PYTH_RECEIVER_PROGRAM_ID,
+        PieError::InvalidPriceFeed
+    );
+    let data = price_update.try_borrow_data()?;
+    require!(
+        data.len() >= PRICE_MESSAGE_OFFSET + PRICE_MESSAGE_LEN
+            && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR
+            && data[40] == VERIFICATION_LEVEL_FULL,
+        PieError::InvalidPriceFeed
+    );
+
+    let message = &data[PRICE_MESSAGE_OFFSET..PRICE_MESSAGE_OFFSET + PRICE_MESSAGE_LEN];
+    require!(
+        message[..32] == price_feed.feed_id,
+        PieError::InvalidPriceFeed
+    );
+    let price = i64::from_le_bytes(message[32..40].try_into().unwrap());
+    let conf = u64::from_le_bytes(message[40..48].try_into().unwrap());
+    let expo = i32::from_le_bytes(message[48..52].try_into().unwrap());
+    let publish_time = i64::from_le_bytes(message[52..60].try_into().unwrap());
+
+    require!(price > 0, PieError::InvalidPrice);
+    let age = now.saturating_sub(publish_time);
+    require!(
+        age <= price_feed.max_staleness_secs.try_into().unwrap_or(i64::MAX),
+        PieError::StalePrice
+    );
+    require!(
+        u128::from(conf) * u128::from(BASIS_POINTS)
+            <= price as u128 * u128::from(price_feed.max_confidence_bp),
+        PieError::PriceConfidenceTooWide
+    );
+
+    Ok(OraclePrice {
+        price: price as u64,
+        conf,
+        expo,
+        publish_time,
+    })
+}
+
+/// Value of `amount` raw units of a token with `decimals` at `price`,
+/// in quote units scaled by 10^VALUE_DECIMALS.
+pub fn value_in_quote(amount: u64, decimals: u8, price: &OraclePrice) -> Result<u128> {
+    let value = u128::from(amount)
+        .checked_mul(price.price.into())
+        .ok_or(PieError::InvalidPrice)?;
+    let exponent = VALUE_DECIMALS + price.expo - i32::from(decimals);
+    let scale = 10u128
+        .checked_pow(exponent.unsigned_abs())
+        .ok_or(PieError::InvalidPrice)?;
+
+    if exponent >= 0 {
+        Ok(value.checked_mul(scale).ok_or(PieError::InvalidPrice)?)
+    } else {
+        Ok(value / scale)
+    }
+}
+
+/// Converts a value scaled by 10^VALUE_DECIMALS to a value stored on accounts.
+pub fn to_stored_value(value: u128, round_up: bool) -> Result<u64> {
+    let scale = 10u128.pow((VALUE_DECIMALS - STORED_VALUE_DECIMALS) as u32);
+    let stored_value = if round_up {
+        value.div_ceil(scale)
+    } else {
+        value / scale
+    };
+
+    Ok(Calculator::to_u64(stored_value)?)
+}
+
+/// Converts `value` into whole units of the token priced at `quote_price`.
+/// Both values are scaled by 10^VALUE_DECIMALS.
+pub fn convert_to_quote_token(value: u128, quote_price: &OraclePrice) -> Result<u128> {
+    let unit_value = value_in_quote(1, 0, quote_price)?;
+    require!(unit_value > 0, PieError::InvalidPrice);
+
+    Ok(value
+        .checked_mul(10u128.pow(VALUE_DECIMALS as u32))
+        .ok_or(PieError::InvalidPrice)?
+        / unit_value)
+}
+
+/// Computes the basket NAV from component prices given in `components` order.
+/// Component prices are quoted in USD; the NAV is converted to SOL when `sol_price` is set.
+pub fn calculate_basket_nav(
+    components: &[BasketComponent],
+    prices: &[OraclePrice],
+    total_supply: u64,
+    sol_price: Option<&OraclePrice>,
+) -> Result<BasketNav> {
+    require!(prices.len() == components.len(), PieError::InvalidPriceFeed);
+
+    let mut aum: u128 = 0;
+    for (component, price) in components.iter().zip(prices) {
+        let amount = Calculator::restore_raw_decimal(
+            component
+                .quantity_in_sys_decimal
+                .checked_mul(total_supply.into())
+                .ok_or(PieError::InvalidQuantity)?,
+        );
+        aum = aum
+            .checked_add(value_in_quote(amount, component.decimals, price)?)
+            .ok_or(PieError::InvalidPrice)?;
+    }
+
+    if let Some(sol_price) = sol_price {
+        aum = convert_to_quote_token(aum, sol_price)?;
+    }
+
+    let nav_per_token = if total_supply == 0 {
+        0
+    } else {
+        aum.checked_mul(10u128.pow(BASKET_DECIMALS.into()))
+            .ok_or(PieError::InvalidPrice)?
+            / u128::from(total_supply)
+    };
+
+    Ok(BasketNav { aum, nav_per_token })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED_ID: [u8; 32] = [7; 32];
+    const NOW: i64 = 1_700_000_000;
+
+    fn price_feed() -> PriceFeed {
+        PriceFeed {
+            bump: 255,
+            mint: Pubkey::new_unique(),
+            feed_id: FEED_ID,
+            max_staleness_secs: 60,
+            max_confidence_bp: 100,
+            source: PriceSourceKind::Pyth,
+            pool: Pubkey::default(),
+            twap_window_secs: 0,
+            reserved: [0; 19],
+        }
+    }
+
+    fn price_update_data(
+        verification_level: u8,
+        feed_id: [u8; 32],
+        price: i64,
+        conf: u64,
+        publish_time: i64,
+    ) -> Vec<u8> {
+        let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0; 32]); // write_authority
+        data.push(verification_level);
+        data.extend_from_slice(&feed_id);
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&conf.to_le_bytes());
+        data.extend_from_slice(&(-8i32).to_le_bytes());
+        data.extend_from_slice(&publish_time.to_le_bytes());
+        data.extend_from_slice(&(publish_time - 1).to_le_bytes()); // prev_publish_time
+        data.extend_from_slice(&price.to_le_bytes()); // ema_price
+        data.extend_from_slice(&conf.to_le_bytes()); // ema_conf
+        data.extend_from_slice(&0u64.to_le_bytes()); // posted_slot
+        data
+    }
+
+    fn read(mut data: Vec<u8>, owner: Pubkey) -> Result<OraclePrice> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        read_pyth_price(&account, &price_feed(), NOW)
+    }
+
+    #[test]
+    fn test_read_pyth_price() {
+        let data = price_update_data(
+            VERIFICATION_LEVEL_FULL,
+            FEED_ID,
+            150_0000_0000,
+            1_0000_0000,
+            NOW - 10,
+        );
+        let price = read(data, PYTH_RECEIVER_PROGRAM_ID).unwrap();
+        assert_eq!(price.price, 150_0000_0000);
+        assert_eq!(price.expo, -8);
+        assert_eq!(price.publish_time, NOW - 10);
+    }
+
+    #[test]
+    fn test_read_pyth_price_rejects_invalid_updates() {
+        let valid = |level, feed_id, conf, publish_time| {
+            price_update_data(level, feed_id, 150_0000_0000, conf, publish_time)
+        };
+
+        // Wrong owner
+        assert!(read(
+            valid(VERIFICATION_LEVEL_FULL, FEED_ID, 0, NOW),
+            Pubkey::new_unique()
+        )
+        .is_err());
+        // Partially verified update
+        assert!(read(valid(0, FEED_ID, 0, NOW), PYTH_RECEIVER_PROGRAM_ID).is_err());
+        // Other feed
+        assert!(read(
+            valid(VERIFICATION_LEVEL_FULL, [8; 32], 0, NOW),
+            PYTH_RECEIVER_PROGRAM_ID
+        )
+        .is_err());
+        // Stale
+        assert!(read(
+            valid(VERIFICATION_LEVEL_FULL, FEED_ID, 0, NOW - 61),
+            PYTH_RECEIVER_PROGRAM_ID
+        )
+        .is_err());
+        // Confidence above 1% of the price
+        assert!(read(
+            valid(VERIFICATION_LEVEL_FULL, FEED_ID, 2_0000_0000, NOW),
+            PYTH_RECEIVER_PROGRAM_ID
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_convert_price() {
+        // 0.005 SOL per token at 150 USD per SOL
+        let price_in_sol = OraclePrice {
+            price: 5_000_000_000,
+            conf: 0,
+            expo: -12,
+            publish_time: NOW - 5,
+        };
+        let sol_price = OraclePrice {
+            price: 150_0000_0000,
+            conf: 1_0000_0000,
+            expo: -8,
+            publish_time: NOW - 10,
+        };
+
+        let price = price_in_sol.convert(&sol_price).unwrap();
+        assert_eq!(
+            value_in_quote(1, 0, &price).unwrap(),
+            75 * 10u128.pow(VALUE_DECIMALS as u32 - 2)
+        );
+        assert_eq!(
+            value_in_quote(
+                1,
+                0,
+                &OraclePrice {
+                    price: price.conf,
+                    ..price
+                }
+            )
+            .unwrap(),
+            5 * 10u128.pow(VALUE_DECIMALS as u32 - 3)
+        );
+        assert_eq!(price.publish_time, NOW - 10);
+    }
+
+    #[test]
+    fn test_calculate_basket_nav() {
+        let usd_price = |price| OraclePrice {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: NOW,
+        };
+        // 1 basket token holds 0.5 SOL (9 decimals) and 10 USDC (6 decimals)
+        let components = vec![
+            BasketComponent {
+                mint: Pubkey::new_unique(),
+                quantity_in_sys_decimal: 500_000_000,
+                decimals: 9,
+                target_weight_bp: 0,
+            },
+            BasketComponent {
+                mint: Pubkey::new_unique(),
+                quantity_in_sys_decimal: 10_000_000,
+                decimals: 6,
+                target_weight_bp: 0,
+            },
+        ];
+        let sol_price = usd_price(100_0000_0000);
+        let prices = [sol_price, usd_price(1_0000_0000)];
+
+        // 2 basket tokens worth 60 USD each
+        let nav = calculate_basket_nav(&components, &prices, 2_000_000, None).unwrap();
+        assert_eq!(nav.aum, 120 * 10u128.pow(VALUE_DECIMALS as u32));
+        assert_eq!(nav.nav_per_token, 60 * 10u128.pow(VALUE_DECIMALS as u32));
+
+        // 0.6 SOL per basket token
+        let nav = calculate_basket_nav(&components, &prices, 2_000_000, Some(&sol_price)).unwrap();
+        assert_eq!(nav.nav_per_token, 6 * 10u128.pow(VALUE_DECIMALS as u32 - 1));
+    }
+}
@@ -0,0 +1,183 @@
+use anchor_lang::{prelude::*, InstructionData};
+use raydium_amm_cpi::library::{AmmInstruction, SwapInstructionBaseIn, SwapInstructionBaseOut};
+
+use crate::utils::{check_swap_accounts, SwapAdapter};
+
+/// Positions of the swap authority, source and destination token accounts in a Raydium AMM swap
+pub const RAYDIUM_AMM_SWAP_POSITIONS: [usize; 3] = [16, 14, 15];
+pub const RAYDIUM_AMM_SWAP_ACCOUNTS_LEN: usize = 17;
+
+/// Positions of the swap authority, source and destination token accounts in a Raydium CPMM swap
+pub const RAYDIUM_CPMM_SWAP_POSITIONS: [usize; 3] = [0, 4, 5];
+pub const RAYDIUM_CPMM_SWAP_ACCOUNTS_LEN: usize = 13;
+
+/// Positions of the swap authority, source and destination token accounts in a Raydium CLMM
+/// `swap_v2`, the tick arrays follow the fixed accounts
+pub const RAYDIUM_CLMM_SWAP_POSITIONS: [usize; 3] = [0, 3, 4];
+pub const RAYDIUM_CLMM_SWAP_ACCOUNTS_LEN: usize = 13;
+
+/// Raydium AMM v4 swap.
+/// `amount` is the input amount and `other_amount_threshold` the minimum output, or the
+/// output amount and the maximum input if `is_swap_base_out`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RaydiumAmmSwap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub is_swap_base_out: bool,
+}
+
+impl SwapAdapter for RaydiumAmmSwap {
+    fn program_id(&self) -> Pubkey {
+        raydium_amm_cpi::ID
+    }
+
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()> {
+        check_swap_accounts(
+            accounts,
+            RAYDIUM_AMM_SWAP_ACCOUNTS_LEN,
+            RAYDIUM_AMM_SWAP_POSITIONS,
+            authority,
+            source,
+            destination,
+        )
+    }
+
+    fn instruction_data(&self) -> Result<Vec<u8>> {
+        let instruction = if self.is_swap_base_out {
+            AmmInstruction::SwapBaseOut(SwapInstructionBaseOut {
+                max_amount_in: self.other_amount_threshold,
+                amount_out: self.amount,
+            })
+        } else {
+            AmmInstruction::SwapBaseIn(SwapInstructionBaseIn {
+                amount_in: self.amount,
+                minimum_amount_out: self.other_amount_threshold,
+            })
+        };
+        Ok(instruction.pack()?)
+    }
+}
+
+/// Raydium CPMM swap, see `RaydiumAmmSwap` for the amounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RaydiumCpmmSwap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub is_swap_base_out: bool,
+}
+
+impl SwapAdapter for RaydiumCpmmSwap {
+    fn program_id(&self) -> Pubkey {
+        raydium_cpmm_cpi::ID
+    }
+
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()> {
+        check_swap_accounts(
+            accounts,
+            RAYDIUM_CPMM_SWAP_ACCOUNTS_LEN,
+            RAYDIUM_CPMM_SWAP_POSITIONS,
+            authority,
+            source,
+            destination,
+        )
+    }
+
+    fn instruction_data(&self) -> Result<Vec<u8>> {
+        let data = if self.is_swap_base_out {
+            raydium_cpmm_cpi::instruction::SwapBaseOutput {
+                max_amount_in: self.other_amount_threshold,
+                amount_out: self.amount,
+            }
+            .data()
+        } else {
+            raydium_cpmm_cpi::instruction::SwapBaseInput {
+                amount_in: self.amount,
+                minimum_amount_out: self.other_amount_threshold,
+            }
+            .data()
+        };
+        Ok(data)
+    }
+}
+
+/// Raydium CLMM swap, see `RaydiumAmmSwap` for the amounts.
+/// A zero `sqrt_price_limit_x64` lets the swap cross any price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RaydiumClmmSwap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit_x64: u128,
+    pub is_swap_base_out: bool,
+}
+
+impl SwapAdapter for RaydiumClmmSwap {
+    fn program_id(&self) -> Pubkey {
+        raydium_clmm_cpi::ID
+    }
+
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()> {
+        check_swap_accounts(
+            accounts,
+            RAYDIUM_CLMM_SWAP_ACCOUNTS_LEN,
+            RAYDIUM_CLMM_SWAP_POSITIONS,
+            authority,
+            source,
+            destination,
+        )
+    }
+
+    fn instruction_data(&self) -> Result<Vec<u8>> {
+        Ok(raydium_clmm_cpi::instruction::SwapV2 {
+            amount: self.amount,
+            other_amount_threshold: self.other_amount_threshold,
+            sqrt_price_limit_x64: self.sqrt_price_limit_x64,
+            is_base_input: !self.is_swap_base_out,
+        }
+        .data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raydium_amm_instruction_data() {
+        let swap = RaydiumAmmSwap {
+            amount: 1_000,
+            other_amount_threshold: 900,
+            is_swap_base_out: false,
+        };
+        let data = swap.instruction_data().unwrap();
+        assert_eq!(data[0], 9);
+        assert_eq!(data[1..9], 1_000u64.to_le_bytes());
+        assert_eq!(data[9..17], 900u64.to_le_bytes());
+
+        let swap = RaydiumAmmSwap {
+            is_swap_base_out: true,
+            ..swap
+        };
+        let data = swap.instruction_data().unwrap();
+        assert_eq!(data[0], 11);
+        assert_eq!(data[1..9], 900u64.to_le_bytes());
+        assert_eq!(data[9..17], 1_000u64.to_le_bytes());
+    }
+}
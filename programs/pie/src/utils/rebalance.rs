@@ -1,6 +1,6 @@
 use crate::error::PieError;
 use crate::utils::Calculator;
-use crate::BasketConfig;
+use crate::{BasketConfig, BASIS_POINTS};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::TokenAccount;
 
@@ -86,4 +86,95 @@ impl Rebalance {
             final_available_destination_balance,
         ))
     }
+
+    /// Checks that the value weight of each component is within `tolerance_bp`
+    /// of its target weight. `values` must share the same quote and scale.
+    pub fn check_target_weights(
+        values: &[u128],
+        target_weights_bp: &[u64],
+        tolerance_bp: u64,
+    ) -> Result<()> {
+        let total_value = values
+            .iter()
+            .try_fold(0u128, |total, value| total.checked_add(*value))
+            .ok_or(PieError::InvalidPrice)?;
+        require!(total_value > 0, PieError::TargetWeightsNotMet);
+
+        for (value, target_weight_bp) in values.iter().zip(target_weights_bp) {
+            let weight_bp = value
+                .checked_mul(BASIS_POINTS.into())
+                .ok_or(PieError::InvalidPrice)?
+                / total_value;
+            require!(
+                weight_bp.abs_diff((*target_weight_bp).into()) <= tolerance_bp.into(),
+                PieError::TargetWeightsNotMet
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks the loss of a rebalancing swap, from `input_value` to `output_value`, against
+    /// the basket slippage limit and the session loss budget.
+    /// Values are scaled by 10^STORED_VALUE_DECIMALS. Returns the new session loss.
+    pub fn check_rebalance_loss(
+        input_value: u64,
+        output_value: u64,
+        max_slippage_bp: u64,
+        session_start_value: u64,
+        session_loss: u64,
+        max_session_loss_bp: u64,
+    ) -> Result<u64> {
+        let loss = input_value.saturating_sub(output_value);
+        require!(
+            u128::from(loss) * u128::from(BASIS_POINTS)
+                <= u128::from(input_value) * u128::from(max_slippage_bp),
+            PieError::RebalanceSlippageExceeded
+        );
+
+        let session_loss = session_loss
+            .checked_add(loss)
+            .ok_or(PieError::RebalanceLossBudgetExceeded)?;
+        require!(
+            u128::from(session_loss) * u128::from(BASIS_POINTS)
+                <= u128::from(session_start_value) * u128::from(max_session_loss_bp),
+            PieError::RebalanceLossBudgetExceeded
+        );
+
+        Ok(session_loss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_target_weights() {
+        // 60/40 basket
+        assert!(Rebalance::check_target_weights(&[600, 400], &[6_000, 4_000], 0).is_ok());
+        // 62/38 is within 2% but not within 1%
+        assert!(Rebalance::check_target_weights(&[620, 380], &[6_000, 4_000], 200).is_ok());
+        assert!(Rebalance::check_target_weights(&[620, 380], &[6_000, 4_000], 100).is_err());
+        // Empty basket can not be checked
+        assert!(Rebalance::check_target_weights(&[0, 0], &[6_000, 4_000], 100).is_err());
+    }
+
+    #[test]
+    fn test_check_rebalance_loss() {
+        // 1% loss on a 1_000 swap with 2% slippage and a 0.5% session budget of 100_000
+        assert_eq!(
+            Rebalance::check_rebalance_loss(1_000, 990, 200, 100_000, 0, 50).unwrap(),
+            10
+        );
+        // Gains do not count as losses
+        assert_eq!(
+            Rebalance::check_rebalance_loss(1_000, 1_010, 200, 100_000, 10, 50).unwrap(),
+            10
+        );
+        // 3% loss exceeds the slippage limit
+        assert!(Rebalance::check_rebalance_loss(1_000, 970, 200, 100_000, 0, 50).is_err());
+        // Session loss of 510 exceeds the 500 budget
+        assert!(Rebalance::check_rebalance_loss(1_000, 990, 200, 100_000, 500, 50).is_err());
+    }
 }
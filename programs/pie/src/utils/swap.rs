@@ -0,0 +1,322 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
+use anchor_spl::{token, token_2022, token_interface::TokenAccount};
+
+use crate::{
+    constant::BASKET_CONFIG,
+    error::PieError,
+    utils::{
+        JupiterSwap, MeteoraDlmmSwap, RaydiumAmmSwap, RaydiumClmmSwap, RaydiumCpmmSwap,
+        WhirlpoolSwap, JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR,
+    },
+    BasketConfig,
+};
+
+/// Swap prices are the raw output amount per raw input amount scaled by 10^SWAP_PRICE_DECIMALS
+pub const SWAP_PRICE_DECIMALS: u32 = 12;
+
+/// Bounds checked on the vault balance changes of a swap, on top of the route's own slippage
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SwapBounds {
+    pub min_amount_out: Option<u64>,
+    pub max_amount_in: Option<u64>,
+}
+
+/// Result of a swap measured on the vault balances
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapOutcome {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// Effective price, see `SWAP_PRICE_DECIMALS`
+    pub price: u128,
+}
+
+impl SwapOutcome {
+    pub fn new(amount_in: u64, amount_out: u64) -> Result<Self> {
+        require!(amount_in > 0, PieError::InvalidSwapResult);
+        require!(amount_out > 0, PieError::InvalidSwapResult);
+
+        let price = u128::from(amount_out)
+            .checked_mul(10u128.pow(SWAP_PRICE_DECIMALS))
+            .ok_or(PieError::ConversionFailure)?
+            / u128::from(amount_in);
+
+        Ok(Self {
+            amount_in,
+            amount_out,
+            price,
+        })
+    }
+
+    pub fn check_bounds(&self, bounds: SwapBounds) -> Result<()> {
+        if let Some(min_amount_out) = bounds.min_amount_out {
+            require!(
+                self.amount_out >= min_amount_out,
+                PieError::SwapAmountOutTooLow
+            );
+        }
+        if let Some(max_amount_in) = bounds.max_amount_in {
+            require!(
+                self.amount_in <= max_amount_in,
+                PieError::SwapAmountInTooHigh
+            );
+        }
+        Ok(())
+    }
+}
+
+#[event]
+pub struct SwapEvent {
+    pub basket_id: u64,
+    pub swap_program: Pubkey,
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub price: u128,
+}
+
+/// A venue the basket PDA can swap through
+pub trait SwapAdapter {
+    /// Program executing the swap
+    fn program_id(&self) -> Pubkey;
+
+    /// Checks that the venue `accounts` swap from `source` to `destination` on behalf of
+    /// `authority`, the only signer of the swap
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()>;
+
+    /// Instruction data of the swap
+    fn instruction_data(&self) -> Result<Vec<u8>>;
+}
+
+/// Venue and venue specific arguments of a swap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SwapRequest {
+    Jupiter(JupiterSwap),
+    RaydiumAmm(RaydiumAmmSwap),
+    RaydiumCpmm(RaydiumCpmmSwap),
+    RaydiumClmm(RaydiumClmmSwap),
+    Whirlpool(WhirlpoolSwap),
+    MeteoraDlmm(MeteoraDlmmSwap),
+}
+
+impl SwapRequest {
+    pub fn adapter(&self) -> &dyn SwapAdapter {
+        match self {
+            SwapRequest::Jupiter(swap) => swap,
+            SwapRequest::RaydiumAmm(swap) => swap,
+            SwapRequest::RaydiumCpmm(swap) => swap,
+            SwapRequest::RaydiumClmm(swap) => swap,
+            SwapRequest::Whirlpool(swap) => swap,
+            SwapRequest::MeteoraDlmm(swap) => swap,
+        }
+    }
+
+    /// Whether the swap is exact out: the output amount is fixed and the input is bounded
+    pub fn is_swap_base_out(&self) -> bool {
+        match self {
+            SwapRequest::Jupiter(swap) => swap
+                .data
+                .starts_with(&JUPITER_EXACT_OUT_ROUTE_DISCRIMINATOR),
+            SwapRequest::RaydiumAmm(swap) => swap.is_swap_base_out,
+            SwapRequest::RaydiumCpmm(swap) => swap.is_swap_base_out,
+            SwapRequest::RaydiumClmm(swap) => swap.is_swap_base_out,
+            SwapRequest::Whirlpool(swap) => swap.is_swap_base_out,
+            SwapRequest::MeteoraDlmm(swap) => swap.is_swap_base_out,
+        }
+    }
+}
+
+/// Checks the fixed accounts of a venue: `positions` are the indexes of the swap authority,
+/// the source and the destination token accounts in `accounts`.
+pub fn check_swap_accounts(
+    accounts: &[AccountInfo],
+    min_accounts_len: usize,
+    positions: [usize; 3],
+    authority: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+) -> Result<()> {
+    require!(
+        accounts.len() >= min_accounts_len,
+        PieError::InvalidSwapAccounts
+    );
+    let [authority_index, source_index, destination_index] = positions;
+    require!(
+        accounts[authority_index].key == authority
+            && accounts[source_index].key == source
+            && accounts[destination_index].key == destination,
+        PieError::InvalidSwapAccounts
+    );
+
+    check_no_other_token_accounts(accounts, authority, source, destination)
+}
+
+/// Rejects swaps touching token accounts of `authority` other than `source` and `destination`,
+/// as the signature of `authority` would let the venue move their tokens too.
+pub fn check_no_other_token_accounts(
+    accounts: &[AccountInfo],
+    authority: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+) -> Result<()> {
+    for account in accounts {
+        if account.key == source || account.key == destination {
+            continue;
+        }
+        if *account.owner != token::ID && *account.owner != token_2022::ID {
+            continue;
+        }
+        // Mints and other token program accounts fail to deserialize as token accounts
+        if let Ok(token_account) = load_token_account(account) {
+            require!(
+                token_account.owner != *authority,
+                PieError::InvalidSwapAccounts
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Swaps from `source` to `destination` through the venue of `request`, signed by the
+/// basket PDA. The venue accounts are checked by the venue adapter before signing.
+/// Token accounts held by the caller as `InterfaceAccount` must be reloaded afterwards.
+pub fn execute_swap<'info>(
+    basket_config: &Account<BasketConfig>,
+    swap_program: &AccountInfo<'info>,
+    venue_accounts: &[AccountInfo<'info>],
+    source: &AccountInfo,
+    destination: &AccountInfo,
+    request: &SwapRequest,
+    bounds: SwapBounds,
+) -> Result<SwapOutcome> {
+    let adapter = request.adapter();
+    require!(
+        swap_program.key() == adapter.program_id(),
+        PieError::InvalidSwapProgram
+    );
+
+    let authority = basket_config.key();
+    adapter.check_accounts(venue_accounts, &authority, source.key, destination.key)?;
+
+    let source_before = load_token_account(source)?;
+    let destination_before = load_token_account(destination)?;
+
+    let accounts: Vec<AccountMeta> = venue_accounts
+        .iter()
+        .map(|acc| AccountMeta {
+            pubkey: *acc.key,
+            is_signer: *acc.key == authority,
+            is_writable: acc.is_writable,
+        })
+        .collect();
+
+    let mut account_infos = venue_accounts.to_vec();
+    account_infos.push(swap_program.clone());
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BASKET_CONFIG,
+        &basket_config.id.to_be_bytes(),
+        &[basket_config.bump],
+    ]];
+
+    invoke_signed(
+        &Instruction {
+            program_id: swap_program.key(),
+            accounts,
+            data: adapter.instruction_data()?,
+        },
+        &account_infos,
+        signer_seeds,
+    )?;
+
+    let outcome = SwapOutcome::new(
+        source_before
+            .amount
+            .checked_sub(load_token_account(source)?.amount)
+            .ok_or(PieError::InvalidSwapResult)?,
+        load_token_account(destination)?
+            .amount
+            .checked_sub(destination_before.amount)
+            .ok_or(PieError::InvalidSwapResult)?,
+    )?;
+    outcome.check_bounds(bounds)?;
+
+    emit!(SwapEvent {
+        basket_id: basket_config.id,
+        swap_program: swap_program.key(),
+        source_mint: source_before.mint,
+        destination_mint: destination_before.mint,
+        amount_in: outcome.amount_in,
+        amount_out: outcome.amount_out,
+        price: outcome.price,
+    });
+
+    Ok(outcome)
+}
+
+/// `execute_swap` through the Jupiter route encoded in `data`
+pub fn execute_jupiter_swap<'info>(
+    basket_config: &Account<BasketConfig>,
+    jupiter_program: &AccountInfo<'info>,
+    jupiter_accounts: &[AccountInfo<'info>],
+    source: &AccountInfo,
+    destination: &AccountInfo,
+    data: Vec<u8>,
+    bounds: SwapBounds,
+) -> Result<SwapOutcome> {
+    execute_swap(
+        basket_config,
+        jupiter_program,
+        jupiter_accounts,
+        source,
+        destination,
+        &SwapRequest::Jupiter(JupiterSwap { data }),
+        bounds,
+    )
+}
+
+fn load_token_account(account: &AccountInfo) -> Result<TokenAccount> {
+    TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_outcome() {
+        let outcome = SwapOutcome::new(2_000, 500).unwrap();
+        assert_eq!(outcome.price, 250_000_000_000);
+
+        assert!(outcome
+            .check_bounds(SwapBounds {
+                min_amount_out: Some(500),
+                max_amount_in: Some(2_000),
+            })
+            .is_ok());
+        assert!(outcome
+            .check_bounds(SwapBounds {
+                min_amount_out: Some(501),
+                max_amount_in: None,
+            })
+            .is_err());
+        assert!(outcome
+            .check_bounds(SwapBounds {
+                min_amount_out: None,
+                max_amount_in: Some(1_999),
+            })
+            .is_err());
+
+        assert!(SwapOutcome::new(0, 500).is_err());
+    }
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
     token::{self},
-    token_interface::TokenAccount,
+    token_2022,
+    token_interface::{Mint, TokenAccount},
 };
 
-use crate::BASIS_POINTS;
+use crate::{error::PieError, states::TokenRegistryEntry, BASIS_POINTS, SECONDS_PER_YEAR};
 
 pub fn transfer_from_pool_vault_to_user<'info>(
     from_vault: &AccountInfo<'info>,
@@ -57,6 +59,116 @@ pub fn transfer_from_user_to_pool_vault<'info>(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_from_pool_vault_to_user<'info>(
+    from_vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: from_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
+
+pub fn transfer_checked_from_user_to_pool_vault<'info>(
+    from: &AccountInfo<'info>,
+    to_vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token_2022::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to_vault.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+/// Checks that `mint` is the `expected_mint` account owned by the token or token-2022 program.
+/// Returns the mint decimals and the token program owning the mint.
+pub fn read_component_mint(expected_mint: &Pubkey, mint: &AccountInfo) -> Result<(u8, Pubkey)> {
+    require!(mint.key == expected_mint, PieError::InvalidComponent);
+
+    let token_program = *mint.owner;
+    require!(
+        token_program == token::ID || token_program == token_2022::ID,
+        PieError::InvalidTokenProgram
+    );
+    let decimals = Mint::try_deserialize(&mut &mint.try_borrow_data()?[..])?.decimals;
+
+    Ok((decimals, token_program))
+}
+
+/// Checks that the token registry entry passed for `mint` lists it as approved.
+pub fn check_token_approved(mint: &Pubkey, entry: &AccountInfo) -> Result<()> {
+    require!(*entry.owner == crate::ID, PieError::TokenNotApproved);
+    TokenRegistryEntry::try_deserialize(&mut &entry.try_borrow_data()?[..])?.check_approved(mint)
+}
+
+/// Validates the `[mint, user_token_account, vault_token_account]` accounts passed in
+/// `remaining_accounts` for an in-kind transfer of `component_mint`.
+/// Returns the mint decimals and the token program owning the mint.
+pub fn validate_in_kind_component_accounts(
+    basket_config: &Pubkey,
+    user: &Pubkey,
+    component_mint: &Pubkey,
+    mint: &AccountInfo,
+    user_token_account: &AccountInfo,
+    vault_token_account: &AccountInfo,
+) -> Result<(u8, Pubkey)> {
+    let (decimals, token_program) = read_component_mint(component_mint, mint)?;
+
+    require!(
+        *vault_token_account.key
+            == get_associated_token_address_with_program_id(
+                basket_config,
+                component_mint,
+                &token_program
+            ),
+        PieError::InvalidRemainingAccounts
+    );
+
+    let user_token = TokenAccount::try_deserialize(&mut &user_token_account.try_borrow_data()?[..])?;
+    require!(
+        user_token.owner == *user && user_token.mint == *component_mint,
+        PieError::InvalidRemainingAccounts
+    );
+
+    Ok((decimals, token_program))
+}
+
 pub fn transfer_fees<'info>(
     source_account: &AccountInfo<'info>,
     platform_fee_account: &AccountInfo<'info>,
@@ -109,17 +221,126 @@ pub fn calculate_fee_amount(
     Ok((platform_fee_amount, creator_fee_amount))
 }
 
-pub fn calculate_amounts_swapped_and_received<'info>(
-    token_source: &Box<InterfaceAccount<'info, TokenAccount>>,
-    token_destination: &Box<InterfaceAccount<'info, TokenAccount>>,
-    balance_in_before: u64,
-    balance_out_before: u64,
+/// Calculates how many basket tokens accrue as management fee for `elapsed` seconds
+/// at an annualized rate of `management_fee_bp` on the current `total_supply`.
+pub fn calculate_management_fee_amount(
+    total_supply: u64,
+    management_fee_bp: u64,
+    elapsed: u64,
+) -> Result<u64> {
+    let fee_amount = u128::from(total_supply)
+        .checked_mul(management_fee_bp.into())
+        .unwrap()
+        .checked_mul(elapsed.into())
+        .unwrap()
+        .checked_div(u128::from(BASIS_POINTS) * u128::from(SECONDS_PER_YEAR))
+        .unwrap();
+    fee_amount
+        .try_into()
+        .map_err(|_| PieError::ConversionFailure.into())
+}
+
+/// Calculates the performance fee for a basket whose NAV per token rose above its
+/// high-water mark. Returns the basket token amount to mint so that existing holders
+/// give up `performance_fee_bp` of the gain, and the post-fee NAV per token that
+/// becomes the new high-water mark.
+pub fn calculate_performance_fee_amount(
+    total_supply: u64,
+    nav_per_token: u64,
+    high_water_mark: u64,
+    performance_fee_bp: u64,
+) -> Result<(u64, u64)> {
+    if nav_per_token <= high_water_mark {
+        return Ok((0, high_water_mark));
+    }
+
+    let fee_per_token = u128::from(nav_per_token - high_water_mark)
+        .checked_mul(performance_fee_bp.into())
+        .unwrap()
+        .checked_div(BASIS_POINTS.into())
+        .unwrap();
+    let nav_after_fee = u128::from(nav_per_token).checked_sub(fee_per_token).unwrap();
+
+    // Minting `fee_amount` dilutes every token by exactly `fee_per_token`
+    let fee_amount: u64 = u128::from(total_supply)
+        .checked_mul(fee_per_token)
+        .unwrap()
+        .checked_div(nav_after_fee)
+        .unwrap()
+        .try_into()
+        .map_err(|_| PieError::ConversionFailure)?;
+
+    Ok((fee_amount, nav_after_fee.try_into().unwrap()))
+}
+
+/// Splits a fee amount between the platform and the creator in the same ratio as
+/// `platform_fee_bp` and `creator_fee_bp`. Everything goes to the creator if both are zero.
+pub fn split_fee_amount(
+    fee_amount: u64,
+    platform_fee_bp: u64,
+    creator_fee_bp: u64,
 ) -> Result<(u64, u64)> {
-    let balance_in_after = token_source.amount;
-    let balance_out_after = token_destination.amount;
+    let total_fee_bp = platform_fee_bp.checked_add(creator_fee_bp).unwrap();
+    if total_fee_bp == 0 {
+        return Ok((0, fee_amount));
+    }
 
-    let amount_swapped = balance_in_before.checked_sub(balance_in_after).unwrap();
-    let amount_received = balance_out_after.checked_sub(balance_out_before).unwrap();
+    let platform_fee_amount: u64 = u128::from(fee_amount)
+        .checked_mul(platform_fee_bp.into())
+        .unwrap()
+        .checked_div(total_fee_bp.into())
+        .unwrap()
+        .try_into()
+        .map_err(|_| PieError::ConversionFailure)?;
+    let creator_fee_amount = fee_amount.checked_sub(platform_fee_amount).unwrap();
 
-    Ok((amount_swapped, amount_received))
+    Ok((platform_fee_amount, creator_fee_amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_management_fee_amount() {
+        // 2% per year on 1_000 basket tokens for a full year
+        assert_eq!(
+            calculate_management_fee_amount(1_000_000_000, 200, SECONDS_PER_YEAR).unwrap(),
+            20_000_000
+        );
+        // half a year
+        assert_eq!(
+            calculate_management_fee_amount(1_000_000_000, 200, SECONDS_PER_YEAR / 2).unwrap(),
+            10_000_000
+        );
+        assert_eq!(calculate_management_fee_amount(0, 200, SECONDS_PER_YEAR).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_calculate_performance_fee_amount() {
+        // NAV went from 1.0 to 1.2 SOL, 20% performance fee -> 0.04 SOL per token
+        let (fee_amount, new_high_water_mark) = calculate_performance_fee_amount(
+            1_000_000_000,
+            1_200_000_000,
+            1_000_000_000,
+            2_000,
+        )
+        .unwrap();
+        assert_eq!(new_high_water_mark, 1_160_000_000);
+        assert_eq!(fee_amount, 34_482_758);
+
+        // Below the high-water mark nothing is charged
+        assert_eq!(
+            calculate_performance_fee_amount(1_000_000_000, 900_000_000, 1_000_000_000, 2_000)
+                .unwrap(),
+            (0, 1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_split_fee_amount() {
+        assert_eq!(split_fee_amount(1_000, 50, 150).unwrap(), (250, 750));
+        assert_eq!(split_fee_amount(1_000, 0, 0).unwrap(), (0, 1_000));
+        assert_eq!(split_fee_amount(1_001, 100, 100).unwrap(), (500, 501));
+    }
 }
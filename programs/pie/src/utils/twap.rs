@@ -0,0 +1,416 @@
+use anchor_lang::prelude::*;
+use raydium_clmm_cpi::states as clmm;
+use raydium_cpmm_cpi::states as cpmm;
+
+use crate::{
+    constant::MIN_TWAP_WINDOW_SECS,
+    error::PieError,
+    utils::{Calculator, OraclePrice, PriceSource},
+};
+
+/// TWAP prices are the raw token ratio scaled by 10^TWAP_PRICE_DECIMALS
+pub const TWAP_PRICE_DECIMALS: i32 = 12;
+/// 1.0 in the Q32.32 prices of CPMM observations
+const Q32: u128 = 1 << 32;
+
+/// Time-weighted price of `base_mint` read from the observations of a Raydium CLMM pool.
+/// The price is quoted in the other token of the pool.
+pub struct ClmmTwapPriceSource<'a> {
+    pub pool: &'a clmm::PoolState,
+    pub observation_state: &'a clmm::ObservationState,
+    pub base_mint: Pubkey,
+    pub window_secs: u32,
+    pub max_staleness_secs: u64,
+}
+
+impl ClmmTwapPriceSource<'_> {
+    /// The other token of the pool, in which the price is quoted
+    pub fn quote_mint(&self) -> Result<Pubkey> {
+        if self.base_mint == self.pool.token_mint_0 {
+            Ok(self.pool.token_mint_1)
+        } else {
+            require!(
+                self.base_mint == self.pool.token_mint_1,
+                PieError::InvalidPriceFeed
+            );
+            Ok(self.pool.token_mint_0)
+        }
+    }
+}
+
+impl PriceSource for ClmmTwapPriceSource<'_> {
+    fn get_price(&self, now: i64) -> Result<OraclePrice> {
+        let pool = self.pool;
+        let base_is_token_0 = self.quote_mint()? == pool.token_mint_1;
+
+        let (sqrt_price_x64, newest_timestamp) = clmm_twap_sqrt_price_x64(
+            &self.observation_state.observations,
+            pool.observation_index.into(),
+            now,
+            self.window_secs,
+            self.max_staleness_secs,
+        )?;
+
+        let (sqrt_price_x64, base_decimals, quote_decimals) = if base_is_token_0 {
+            (sqrt_price_x64, pool.mint_decimals_0, pool.mint_decimals_1)
+        } else {
+            require!(sqrt_price_x64 > 1, PieError::InvalidPrice);
+            (
+                u128::MAX / sqrt_price_x64,
+                pool.mint_decimals_1,
+                pool.mint_decimals_0,
+            )
+        };
+
+        let price = sqrt_price_x64_to_price(sqrt_price_x64)?;
+        require!(price > 0, PieError::InvalidPrice);
+
+        Ok(OraclePrice {
+            price,
+            conf: 0,
+            expo: i32::from(base_decimals) - i32::from(quote_decimals) - TWAP_PRICE_DECIMALS,
+            publish_time: newest_timestamp,
+        })
+    }
+}
+
+/// Loads the TWAP of `base_mint` over `window_secs` from a Raydium CLMM pool and its
+/// observation account. Returns the price and the mint it is quoted in.
+pub fn load_clmm_twap_price<'info>(
+    base_mint: &Pubkey,
+    pool: &'info AccountInfo<'info>,
+    observation_state: &'info AccountInfo<'info>,
+    window_secs: u32,
+    max_staleness_secs: u64,
+    now: i64,
+) -> Result<(OraclePrice, Pubkey)> {
+    let pool_loader = AccountLoader::<clmm::PoolState>::try_from(pool)?;
+    let observation_loader = AccountLoader::<clmm::ObservationState>::try_from(observation_state)?;
+    let pool_state = pool_loader.load()?;
+    let observation = observation_loader.load()?;
+    require!(
+        pool_state.observation_key == observation_state.key() && observation.pool_id == pool.key(),
+        PieError::InvalidPriceFeed
+    );
+
+    let source = ClmmTwapPriceSource {
+        pool: &pool_state,
+        observation_state: &observation,
+        base_mint: *base_mint,
+        window_secs,
+        max_staleness_secs,
+    };
+    Ok((source.get_price(now)?, source.quote_mint()?))
+}
+
+/// Time-weighted price of `base_mint` read from the observations of a Raydium CPMM pool.
+/// The price is quoted in the other token of the pool.
+pub struct CpmmTwapPriceSource<'a> {
+    pub pool: &'a cpmm::PoolState,
+    pub observation_state: &'a cpmm::ObservationState,
+    pub base_mint: Pubkey,
+    pub window_secs: u32,
+    pub max_staleness_secs: u64,
+}
+
+impl CpmmTwapPriceSource<'_> {
+    /// The other token of the pool, in which the price is quoted
+    pub fn quote_mint(&self) -> Result<Pubkey> {
+        if self.base_mint == self.pool.token_0_mint {
+            Ok(self.pool.token_1_mint)
+        } else {
+            require!(
+                self.base_mint == self.pool.token_1_mint,
+                PieError::InvalidPriceFeed
+            );
+            Ok(self.pool.token_0_mint)
+        }
+    }
+}
+
+impl PriceSource for CpmmTwapPriceSource<'_> {
+    fn get_price(&self, now: i64) -> Result<OraclePrice> {
+        let pool = self.pool;
+        let base_is_token_0 = self.quote_mint()? == pool.token_1_mint;
+        let (base_decimals, quote_decimals) = if base_is_token_0 {
+            (pool.mint_0_decimals, pool.mint_1_decimals)
+        } else {
+            (pool.mint_1_decimals, pool.mint_0_decimals)
+        };
+
+        let (price_x32, newest_timestamp) = cpmm_twap_price_x32(
+            &self.observation_state.observations,
+            self.observation_state.observation_index.into(),
+            base_is_token_0,
+            now,
+            self.window_secs,
+            self.max_staleness_secs,
+        )?;
+
+        let price = Calculator::to_u64(
+            price_x32
+                .checked_mul(10u128.pow(TWAP_PRICE_DECIMALS as u32))
+                .ok_or(PieError::InvalidPrice)?
+                / Q32,
+        )?;
+        require!(price > 0, PieError::InvalidPrice);
+
+        Ok(OraclePrice {
+            price,
+            conf: 0,
+            expo: i32::from(base_decimals) - i32::from(quote_decimals) - TWAP_PRICE_DECIMALS,
+            publish_time: newest_timestamp,
+        })
+    }
+}
+
+/// Loads the TWAP of `base_mint` over `window_secs` from a Raydium CPMM pool and its
+/// observation account. Returns the price and the mint it is quoted in.
+pub fn load_cpmm_twap_price<'info>(
+    base_mint: &Pubkey,
+    pool: &'info AccountInfo<'info>,
+    observation_state: &'info AccountInfo<'info>,
+    window_secs: u32,
+    max_staleness_secs: u64,
+    now: i64,
+) -> Result<(OraclePrice, Pubkey)> {
+    let pool_loader = AccountLoader::<cpmm::PoolState>::try_from(pool)?;
+    let observation_loader = AccountLoader::<cpmm::ObservationState>::try_from(observation_state)?;
+    let pool_state = pool_loader.load()?;
+    let observation = observation_loader.load()?;
+    require!(
+        pool_state.observation_key == observation_state.key() && observation.pool_id == pool.key(),
+        PieError::InvalidPriceFeed
+    );
+
+    let source = CpmmTwapPriceSource {
+        pool: &pool_state,
+        observation_state: &observation,
+        base_mint: *base_mint,
+        window_secs,
+        max_staleness_secs,
+    };
+    Ok((source.get_price(now)?, source.quote_mint()?))
+}
+
+/// Average sqrt price (Q64.64) of a CLMM pool over at least `window_secs`, see `observation_twap`.
+/// `observations` is the pool's ring buffer, `newest_index` its most recent entry.
+pub fn clmm_twap_sqrt_price_x64(
+    observations: &[clmm::Observation],
+    newest_index: usize,
+    now: i64,
+    window_secs: u32,
+    max_staleness_secs: u64,
+) -> Result<(u128, i64)> {
+    observation_twap(
+        observations.len(),
+        newest_index,
+        |index| {
+            let observation = observations[index];
+            (
+                observation.block_timestamp.into(),
+                observation.cumulative_time_price_x64,
+            )
+        },
+        now,
+        window_secs,
+        max_staleness_secs,
+    )
+}
+
+/// Average price (Q32.32) of token 0, or of token 1 when `base_is_token_0` is false, of a
+/// CPMM pool over at least `window_secs`, see `observation_twap`.
+pub fn cpmm_twap_price_x32(
+    observations: &[cpmm::Observation],
+    newest_index: usize,
+    base_is_token_0: bool,
+    now: i64,
+    window_secs: u32,
+    max_staleness_secs: u64,
+) -> Result<(u128, i64)> {
+    observation_twap(
+        observations.len(),
+        newest_index,
+        |index| {
+            let observation = observations[index];
+            let cumulative = if base_is_token_0 {
+                observation.cumulative_token_0_price_x32
+            } else {
+                observation.cumulative_token_1_price_x32
+            };
+            (
+                observation.block_timestamp.try_into().unwrap_or(i64::MAX),
+                cumulative,
+            )
+        },
+        now,
+        window_secs,
+        max_staleness_secs,
+    )
+}
+
+/// Walks a ring buffer of `(timestamp, cumulative price)` observations back from
+/// `newest_index`. The window ends at the newest observation, which must be at most
+/// `max_staleness_secs` old: extending it to `now` with the current pool price would let a
+/// trade in the same transaction move the average. The window starts at the newest
+/// observation recorded at least `window_secs` before it.
+/// Returns the average price and the timestamp of the newest observation.
+fn observation_twap(
+    len: usize,
+    newest_index: usize,
+    observation: impl Fn(usize) -> (i64, u128),
+    now: i64,
+    window_secs: u32,
+    max_staleness_secs: u64,
+) -> Result<(u128, i64)> {
+    require!(
+        window_secs >= MIN_TWAP_WINDOW_SECS,
+        PieError::TwapWindowTooShort
+    );
+    require!(newest_index < len, PieError::InvalidPriceFeed);
+
+    let (newest_timestamp, newest_cumulative) = observation(newest_index);
+    require!(
+        newest_timestamp > 0 && newest_timestamp <= now,
+        PieError::InvalidPriceFeed
+    );
+    require!(
+        now - newest_timestamp <= max_staleness_secs.try_into().unwrap_or(i64::MAX),
+        PieError::StalePrice
+    );
+
+    let target = newest_timestamp - i64::from(window_secs);
+    let mut index = newest_index;
+    let mut previous_timestamp = newest_timestamp;
+    for _ in 0..len {
+        let (timestamp, cumulative) = observation(index);
+        // Uninitialized entry or wrapped around to a newer one: the history is too short
+        if timestamp == 0 || timestamp > previous_timestamp {
+            break;
+        }
+        if timestamp <= target {
+            let elapsed = (newest_timestamp - timestamp) as u128;
+            return Ok((
+                newest_cumulative.wrapping_sub(cumulative) / elapsed,
+                newest_timestamp,
+            ));
+        }
+        previous_timestamp = timestamp;
+        index = index.checked_sub(1).unwrap_or(len - 1);
+    }
+
+    err!(PieError::TwapWindowTooShort)
+}
+
+/// Converts a Q64.64 sqrt price into the token ratio scaled by 10^TWAP_PRICE_DECIMALS.
+fn sqrt_price_x64_to_price(sqrt_price_x64: u128) -> Result<u64> {
+    let price_x64 = mul_shr_64(sqrt_price_x64, sqrt_price_x64).ok_or(PieError::InvalidPrice)?;
+    let price = mul_shr_64(price_x64, 10u128.pow(TWAP_PRICE_DECIMALS as u32))
+        .ok_or(PieError::InvalidPrice)?;
+
+    Ok(price.try_into().map_err(|_| PieError::InvalidPrice)?)
+}
+
+/// `(a * b) >> 64` without intermediate overflow, `None` when the result exceeds u128.
+fn mul_shr_64(a: u128, b: u128) -> Option<u128> {
+    let (a_hi, a_lo) = (a >> 64, a & u128::from(u64::MAX));
+    let (b_hi, b_lo) = (b >> 64, b & u128::from(u64::MAX));
+
+    let high = a_hi.checked_mul(b_hi)?;
+    if high >> 64 != 0 {
+        return None;
+    }
+
+    (high << 64)
+        .checked_add(a_hi.checked_mul(b_lo)?)?
+        .checked_add(a_lo.checked_mul(b_hi)?)?
+        .checked_add((a_lo * b_lo) >> 64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const ONE_X64: u128 = 1 << 64;
+
+    fn observation(block_timestamp: i64, cumulative_time_price_x64: u128) -> clmm::Observation {
+        clmm::Observation {
+            block_timestamp: block_timestamp as u32,
+            sqrt_price_x64: 0,
+            cumulative_time_price_x64,
+            padding: 0,
+        }
+    }
+
+    #[test]
+    fn test_clmm_twap_sqrt_price_x64() {
+        // sqrt price 1 for 900s, then 3 for 300s, ring buffer wrapped at index 1
+        let observations = [
+            observation(NOW - 300, 900 * ONE_X64),
+            observation(NOW, 1_800 * ONE_X64),
+            observation(NOW - 1_200, 0),
+            observation(NOW - 900, 300 * ONE_X64),
+        ];
+
+        // (1800 - 0) / 1200 over the last 1200s
+        let (twap, newest_timestamp) =
+            clmm_twap_sqrt_price_x64(&observations, 1, NOW, 1_000, 60).unwrap();
+        assert_eq!(twap, 3 * ONE_X64 / 2);
+        assert_eq!(newest_timestamp, NOW);
+
+        // The window ends at the newest observation: (1800 - 300) / 900
+        let (twap, _) = clmm_twap_sqrt_price_x64(&observations, 1, NOW + 60, 400, 60).unwrap();
+        assert_eq!(twap, 1_500 * ONE_X64 / 900);
+
+        // Newest observation older than the max staleness
+        assert!(clmm_twap_sqrt_price_x64(&observations, 1, NOW + 61, 400, 60).is_err());
+        // Window below the minimum
+        assert!(
+            clmm_twap_sqrt_price_x64(&observations, 1, NOW, MIN_TWAP_WINDOW_SECS - 1, 60).is_err()
+        );
+        // Observations do not cover the window
+        assert!(clmm_twap_sqrt_price_x64(&observations, 1, NOW, 1_201, 60).is_err());
+    }
+
+    #[test]
+    fn test_sqrt_price_x64_to_price() {
+        // sqrt price 1.5 => price 2.25
+        assert_eq!(
+            sqrt_price_x64_to_price(3 * ONE_X64 / 2).unwrap(),
+            2_250_000_000_000
+        );
+        // Inverted sqrt price 2 => price 0.25
+        assert_eq!(
+            sqrt_price_x64_to_price(u128::MAX / (2 * ONE_X64)).unwrap(),
+            249_999_999_999
+        );
+        assert!(sqrt_price_x64_to_price(u128::MAX).is_err());
+    }
+
+    #[test]
+    fn test_cpmm_twap_price_x32() {
+        let observation =
+            |block_timestamp: i64, cumulative_0: u128, cumulative_1: u128| cpmm::Observation {
+                block_timestamp: block_timestamp as u64,
+                cumulative_token_0_price_x32: cumulative_0 * Q32,
+                cumulative_token_1_price_x32: cumulative_1 * Q32,
+            };
+        // Token 0 priced 2 then 4 token 1, 300s each
+        let observations = [
+            observation(NOW - 600, 0, 0),
+            observation(NOW - 300, 600, 150),
+            observation(NOW, 1_800, 225),
+        ];
+
+        // (1800 - 0) / 600
+        let (twap, _) = cpmm_twap_price_x32(&observations, 2, true, NOW + 100, 400, 120).unwrap();
+        assert_eq!(twap, 3 * Q32);
+        // Reverse direction: (225 - 150) / 300
+        let (twap, _) = cpmm_twap_price_x32(&observations, 2, false, NOW, 300, 120).unwrap();
+        assert_eq!(twap, Q32 / 4);
+
+        assert!(cpmm_twap_price_x32(&observations, 2, true, NOW + 121, 300, 120).is_err());
+        assert!(cpmm_twap_price_x32(&observations, 2, true, NOW, 601, 120).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::{prelude::*, InstructionData};
+use whirlpool_cpi::states::{MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64};
+
+use crate::utils::{check_swap_accounts, SwapAdapter};
+
+/// Position of the swap authority in a Whirlpool `swap_v2`
+pub const WHIRLPOOL_SWAP_AUTHORITY_POSITION: usize = 3;
+/// Positions of the token A and token B accounts of the swap authority in a Whirlpool `swap_v2`
+pub const WHIRLPOOL_SWAP_TOKEN_A_POSITION: usize = 7;
+pub const WHIRLPOOL_SWAP_TOKEN_B_POSITION: usize = 9;
+pub const WHIRLPOOL_SWAP_ACCOUNTS_LEN: usize = 15;
+
+/// Orca Whirlpool swap through `swap_v2`, see `RaydiumAmmSwap` for the amounts.
+/// A zero `sqrt_price_limit` lets the swap cross any price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WhirlpoolSwap {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub is_swap_base_out: bool,
+    /// Swaps token A of the pool for token B
+    pub a_to_b: bool,
+}
+
+impl WhirlpoolSwap {
+    fn sqrt_price_limit(&self) -> u128 {
+        match self.sqrt_price_limit {
+            0 if self.a_to_b => MIN_SQRT_PRICE_X64,
+            0 => MAX_SQRT_PRICE_X64,
+            limit => limit,
+        }
+    }
+}
+
+impl SwapAdapter for WhirlpoolSwap {
+    fn program_id(&self) -> Pubkey {
+        whirlpool_cpi::ID
+    }
+
+    fn check_accounts(
+        &self,
+        accounts: &[AccountInfo],
+        authority: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+    ) -> Result<()> {
+        let (source_position, destination_position) = if self.a_to_b {
+            (
+                WHIRLPOOL_SWAP_TOKEN_A_POSITION,
+                WHIRLPOOL_SWAP_TOKEN_B_POSITION,
+            )
+        } else {
+            (
+                WHIRLPOOL_SWAP_TOKEN_B_POSITION,
+                WHIRLPOOL_SWAP_TOKEN_A_POSITION,
+            )
+        };
+        check_swap_accounts(
+            accounts,
+            WHIRLPOOL_SWAP_ACCOUNTS_LEN,
+            [
+                WHIRLPOOL_SWAP_AUTHORITY_POSITION,
+                source_position,
+                destination_position,
+            ],
+            authority,
+            source,
+            destination,
+        )
+    }
+
+    fn instruction_data(&self) -> Result<Vec<u8>> {
+        Ok(whirlpool_cpi::instruction::SwapV2 {
+            amount: self.amount,
+            other_amount_threshold: self.other_amount_threshold,
+            sqrt_price_limit: self.sqrt_price_limit(),
+            amount_specified_is_input: !self.is_swap_base_out,
+            a_to_b: self.a_to_b,
+            remaining_accounts_info: None,
+        }
+        .data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whirlpool_sqrt_price_limit() {
+        let swap = WhirlpoolSwap {
+            amount: 1_000,
+            other_amount_threshold: 900,
+            sqrt_price_limit: 0,
+            is_swap_base_out: false,
+            a_to_b: true,
+        };
+        assert_eq!(swap.sqrt_price_limit(), MIN_SQRT_PRICE_X64);
+
+        let swap = WhirlpoolSwap {
+            a_to_b: false,
+            ..swap
+        };
+        assert_eq!(swap.sqrt_price_limit(), MAX_SQRT_PRICE_X64);
+
+        let swap = WhirlpoolSwap {
+            sqrt_price_limit: 1 << 64,
+            ..swap
+        };
+        assert_eq!(swap.sqrt_price_limit(), 1 << 64);
+    }
+}
@@ -7,8 +7,8 @@ import {
 } from "@solana/web3.js";
 import { ProgramStateManager } from "../state";
 import {
-  BasketComponent,
   CreateBasketArgs,
+  CreateBasketComponentArgs,
   CreateBasketWithTokenWeightsArgs,
 } from "../types";
 import {
@@ -37,6 +37,8 @@ export class CreatorInstructions extends ProgramStateManager {
 
   /**
    * Creates a basket.
+   * @dev Passes the mint of each component, followed by its token registry entry unless
+   *      `args.permissionlessComponents` is set.
    * @param creator - The creator account.
    * @param args - The basket arguments.
    * @param basketId - The basket ID.
@@ -54,6 +56,21 @@ export class CreatorInstructions extends ProgramStateManager {
     const programState = await this.getProgramState();
     const basketMint = this.basketMintPDA({ basketId });
 
+    const remainingAccounts = args.components.map((component) => ({
+      pubkey: component.mint,
+      isSigner: false,
+      isWritable: false,
+    }));
+    if (!args.permissionlessComponents) {
+      remainingAccounts.push(
+        ...args.components.map((component) => ({
+          pubkey: this.tokenRegistryEntryPDA({ mint: component.mint }),
+          isSigner: false,
+          isWritable: false,
+        }))
+      );
+    }
+
     const createBasketTx = await this.program.methods
       .createBasket(args)
       .accountsPartial({
@@ -64,6 +81,7 @@ export class CreatorInstructions extends ProgramStateManager {
         basketMint: basketMint,
         platformFeeWallet: programState.platformFeeWallet,
       })
+      .remainingAccounts(remainingAccounts)
       .transaction();
 
     const { tx: createPlatformFeeTokenAccountTx } =
@@ -119,7 +137,7 @@ export class CreatorInstructions extends ProgramStateManager {
       })
     );
 
-    const components: BasketComponent[] = [];
+    const components: CreateBasketComponentArgs[] = [];
 
     for (let i = 0; i < args.tokenWeights.length; i++) {
       const { price, decimals } = tokenPriceAndDecimals[i];
@@ -132,7 +150,8 @@ export class CreatorInstructions extends ProgramStateManager {
 
       components.push({
         mint: args.tokenWeights[i].mint,
-        quantityInSysDecimal,
+        quantity: { sysDecimal: [quantityInSysDecimal] },
+        targetWeightBp: new BN(0),
       });
     }
 
@@ -37,25 +37,64 @@ export class RebalancerInstructions extends ProgramStateManager {
     super(programId, connection);
   }
 
+  /**
+   * Lists the price feed accounts of every component of a basket in component order, as
+   * read by startRebalancing and stopRebalancing.
+   * @param basketConfig - The basket config.
+   * @param priceAccounts - The price feed accounts of each component keyed by mint, starting
+   *                        with its price feed PDA (see `price_accounts_len` in the program).
+   * @returns The price feed accounts of the components.
+   */
+  componentPriceAccounts({
+    basketConfig,
+    priceAccounts,
+  }: {
+    basketConfig: BasketConfig;
+    priceAccounts: Record<string, PublicKey[]>;
+  }): PublicKey[] {
+    return basketConfig.components.flatMap((component) => {
+      const accounts = priceAccounts[component.mint.toBase58()];
+      if (!accounts) {
+        throw new Error(
+          `Missing price feed accounts for ${component.mint.toBase58()}`
+        );
+      }
+      return accounts;
+    });
+  }
+
   /**
    * Starts rebalancing.
    * @param rebalancer - The rebalancer account.
    * @param basketId - The basket ID.
+   * @param priceAccounts - The price feed accounts of every component, required when the
+   *                        basket has rebalance limits (see componentPriceAccounts).
    * @returns A promise that resolves to a transaction.
    */
   async startRebalancing({
     rebalancer,
     basketId,
+    priceAccounts = [],
   }: {
     rebalancer: PublicKey;
     basketId: BN;
+    priceAccounts?: PublicKey[];
   }): Promise<Transaction> {
     return await this.program.methods
       .startRebalancing()
       .accountsPartial({
         rebalancer,
         basketConfig: this.basketConfigPDA({ basketId }),
+        programState: this.programStatePDA(),
+        basketMint: this.basketMintPDA({ basketId }),
       })
+      .remainingAccounts(
+        priceAccounts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
       .transaction();
   }
 
@@ -63,21 +102,33 @@ export class RebalancerInstructions extends ProgramStateManager {
    * Stops rebalancing.
    * @param rebalancer - The rebalancer account.
    * @param basketId - The basket ID.
+   * @param priceAccounts - The price feed accounts of every component, required when the
+   *                        basket has target weights (see componentPriceAccounts).
    * @returns A promise that resolves to a transaction.
    */
   async stopRebalancing({
     rebalancer,
     basketId,
+    priceAccounts = [],
   }: {
     rebalancer: PublicKey;
     basketId: BN;
+    priceAccounts?: PublicKey[];
   }): Promise<Transaction> {
     return await this.program.methods
       .stopRebalancing()
       .accountsPartial({
         rebalancer,
         basketConfig: this.basketConfigPDA({ basketId }),
+        basketMint: this.basketMintPDA({ basketId }),
       })
+      .remainingAccounts(
+        priceAccounts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
       .transaction();
   }
 
@@ -632,6 +683,7 @@ export class RebalanceExecutor {
   private wantComponents: Component[];
   // option
   private maxSlippageBps: number;
+  private priceAccounts?: Record<string, PublicKey[]>;
 
   private signedTxs: VersionedTransaction[];
 
@@ -647,6 +699,9 @@ export class RebalanceExecutor {
     wantComponents: Component[],
     option?: {
       maxSlippageBps?: number;
+      // price feed accounts keyed by component mint, for baskets with rebalance limits or
+      // target weights (see RebalancerInstructions.componentPriceAccounts)
+      priceAccounts?: Record<string, PublicKey[]>;
     }
   ) {
     this.instructor = instructor;
@@ -663,6 +718,7 @@ export class RebalanceExecutor {
     this.curCtx.step += 1;
 
     this.maxSlippageBps = option?.maxSlippageBps || 1000; // 10%
+    this.priceAccounts = option?.priceAccounts;
 
     this.nextTxs = [];
   }
@@ -781,6 +837,16 @@ export class RebalanceExecutor {
     return out;
   }
 
+  private componentPriceAccounts(basketCfg: BasketConfig): PublicKey[] {
+    if (!this.priceAccounts) {
+      return [];
+    }
+    return this.instructor.componentPriceAccounts({
+      basketConfig: basketCfg,
+      priceAccounts: this.priceAccounts,
+    });
+  }
+
   private async basketTotalSupply(): Promise<BN> {
     const totalSupplyResp = await this.connection.getTokenSupply(
       this.basketMint
@@ -891,6 +957,7 @@ export class RebalanceExecutor {
       const startTx = await this.instructor.startRebalancing({
         rebalancer: this.rebalancer,
         basketId: this.basketId,
+        priceAccounts: this.componentPriceAccounts(basketCfg),
       });
 
       const recentBlockhash = (
@@ -1301,6 +1368,7 @@ export class RebalanceExecutor {
       const stopTx = await this.instructor.stopRebalancing({
         rebalancer: this.rebalancer,
         basketId: this.basketId,
+        priceAccounts: this.componentPriceAccounts(basketCfg),
       });
 
       const recentBlockhash = (
@@ -1324,9 +1392,11 @@ export class RebalanceExecutor {
 
   private async abnormalStop() {
     try {
+      const basketCfg = await this.fetchBasketConfig();
       const stopTx = await this.instructor.stopRebalancing({
         rebalancer: this.rebalancer,
         basketId: this.basketId,
+        priceAccounts: this.componentPriceAccounts(basketCfg),
       });
 
       const recentBlockhash = (
@@ -230,19 +230,23 @@ export class UserInstructions extends ProgramStateManager {
    * Mints a basket token.
    * @param user - The user account.
    * @param basketId - The basket ID.
+   * @param minMintAmount - The minimum amount of basket token to mint.
    * @returns A promise that resolves to a transaction.
    */
   async mintBasketToken({
     user,
     basketId,
+    minMintAmount = new BN(0),
   }: {
     user: PublicKey;
     basketId: BN;
+    minMintAmount?: BN;
   }): Promise<Transaction> {
     const tx = new Transaction();
     const basketMint = this.basketMintPDA({ basketId });
     const basketConfig = this.basketConfigPDA({ basketId });
     const userFund = this.userFundPDA({ user, basketId });
+    const userMintRecord = this.userMintRecordPDA({ user, basketId });
 
     const userBalance = await this.getUserBalance({ user });
 
@@ -260,13 +264,14 @@ export class UserInstructions extends ProgramStateManager {
       tx.add(userBasketTokenTx);
     }
     const mintBasketTokenTx = await this.program.methods
-      .mintBasketToken()
+      .mintBasketToken(minMintAmount)
       .accountsPartial({
         user,
         basketConfig,
         userFund,
         basketMint,
         userBasketTokenAccount,
+        userMintRecord,
       })
       .transaction();
     tx.add(mintBasketTokenTx);
@@ -487,27 +492,40 @@ export class UserInstructions extends ProgramStateManager {
    * @param user - The user account.
    * @param basketId - The basket ID.
    * @param amount - The amount.
+   * @param minAmountsOut - The minimum amount of each component, in basket component order.
+   * Defaults to no minimum.
    * @returns A promise that resolves to a transaction.
    */
   async redeemBasketToken({
     user,
     basketId,
     amount,
+    minAmountsOut,
   }: {
     user: PublicKey;
     basketId: BN;
     amount: number;
+    minAmountsOut?: BN[];
   }): Promise<Transaction> {
     const basketMint = this.basketMintPDA({ basketId });
     const basketConfig = this.basketConfigPDA({ basketId });
+    if (!minAmountsOut) {
+      const { components } = await this.getBasketConfig({ basketId });
+      minAmountsOut = components.map(() => new BN(0));
+    }
     console.log(basketMint.toBase58());
     const userBasketTokenAccount = getAssociatedTokenAddressSync(
       basketMint,
       user,
       false
     );
+    // The mint record only exists once the user minted the basket
+    const userMintRecord = this.userMintRecordPDA({ user, basketId });
+    const userMintRecordInfo = await this.connection.getAccountInfo(
+      userMintRecord
+    );
     const redeemBasketTokenTx = await this.program.methods
-      .redeemBasketToken(new BN(amount))
+      .redeemBasketToken(new BN(amount), minAmountsOut)
       .accountsPartial({
         programState: this.programStatePDA(),
         user,
@@ -515,6 +533,7 @@ export class UserInstructions extends ProgramStateManager {
         userFund: this.userFundPDA({ user, basketId }),
         basketMint,
         userBasketTokenAccount: userBasketTokenAccount,
+        userMintRecord: userMintRecordInfo ? userMintRecord : null,
       })
       .transaction();
     return redeemBasketTokenTx;
@@ -827,6 +846,7 @@ export class UserInstructions extends ProgramStateManager {
             user,
             basketId,
             amount: amountInRawDecimal,
+            minAmountsOut: amounts,
           })
         );
       }
@@ -4,6 +4,9 @@ export const USER_FUND = "user_fund";
 export const USER_BALANCE = "user_balance";
 export const BASKET_CONFIG = "basket_config";
 export const BASKET_MINT = "basket_mint";
+export const USER_MINT_RECORD = "user_mint_record";
+export const PRICE_FEED = "price_feed";
+export const TOKEN_REGISTRY = "token_registry";
 
 export const MPL_TOKEN_METADATA_PROGRAM_ID =
   "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
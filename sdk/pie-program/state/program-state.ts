@@ -8,6 +8,9 @@ import {
   BASKET_MINT,
   MPL_TOKEN_METADATA_PROGRAM_ID,
   USER_BALANCE,
+  USER_MINT_RECORD,
+  PRICE_FEED,
+  TOKEN_REGISTRY,
 } from "./constants";
 import * as PieIDL from "../../../target/idl/pie.json";
 import { Pie } from "../../../target/types/pie";
@@ -78,6 +81,37 @@ export class ProgramStateManager {
     )[0];
   }
 
+  userMintRecordPDA({
+    user,
+    basketId,
+  }: {
+    user: PublicKey;
+    basketId: BN;
+  }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(USER_MINT_RECORD),
+        basketId.toArrayLike(Buffer, "be", 8),
+        user.toBuffer(),
+      ],
+      this.programId
+    )[0];
+  }
+
+  priceFeedPDA({ mint }: { mint: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(PRICE_FEED), mint.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  tokenRegistryEntryPDA({ mint }: { mint: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(TOKEN_REGISTRY), mint.toBuffer()],
+      this.programId
+    )[0];
+  }
+
   metadataPDA({ mint }: { mint: PublicKey }): PublicKey {
     return PublicKey.findProgramAddressSync(
       [
@@ -11,6 +11,8 @@ export type UserBalance = IdlAccounts<Pie>["userBalance"];
 // Instruction Types
 export type BasketComponent = IdlTypes<Pie>["basketComponent"];
 export type CreateBasketArgs = IdlTypes<Pie>["createBasketArgs"];
+export type CreateBasketComponentArgs =
+  IdlTypes<Pie>["createBasketComponentArgs"];
 export type CreateBasketWithTokenWeightsArgs = Omit<
   CreateBasketArgs,
   "components"
@@ -30,7 +30,7 @@ import {
 } from "@solana/spl-token";
 import { BN } from "@coral-xyz/anchor";
 import { Raydium, API_URLS } from "@raydium-io/raydium-sdk-v2";
-import { BasketComponent, PieProgram } from "../pie-program";
+import { CreateBasketComponentArgs, PieProgram } from "../pie-program";
 import { Table } from "console-table-printer";
 import axios from "axios";
 import { BuySwapData, TokenInfo, TokenBalance } from "../pie-program/types";
@@ -155,14 +155,15 @@ export async function createBasketComponents(
   connection: Connection,
   creator: Signer,
   ratios: Array<number>
-): Promise<BasketComponent[]> {
-  let components: BasketComponent[] = [];
+): Promise<CreateBasketComponentArgs[]> {
+  let components: CreateBasketComponentArgs[] = [];
   const decimals = 6;
   for (let i = 0; i < ratios.length; i++) {
     const mint = await createNewMint(connection, creator, decimals);
-    const component: BasketComponent = {
+    const component: CreateBasketComponentArgs = {
       mint: mint,
-      quantityInSysDecimal: new BN(ratios[i]),
+      quantity: { sysDecimal: [new BN(ratios[i])] },
+      targetWeightBp: new BN(0),
     };
     components.push(component);
   }
@@ -217,6 +217,14 @@ describe("pie", () => {
         rebalancer: creator.publicKey,
         creatorFeeBp: new BN(50),
         rebalanceType: { dynamic: {} },
+        managementFeeBp: new BN(0),
+        performanceFeeBp: new BN(0),
+        weightToleranceBp: new BN(0),
+        maxSupply: new BN(0),
+        maxHoldingPerWallet: new BN(0),
+        allowlistMode: { none: {} },
+        allowlistRoot: new Array(32).fill(0),
+        permissionlessComponents: true,
       };
       const programState = await pieProgram.state.getProgramState();
       const basketId = programState.basketCounter;
@@ -279,6 +287,14 @@ describe("pie", () => {
         rebalancer: rebalancer.publicKey,
         creatorFeeBp: new BN(50),
         rebalanceType: { dynamic: {} },
+        managementFeeBp: new BN(0),
+        performanceFeeBp: new BN(0),
+        weightToleranceBp: new BN(0),
+        maxSupply: new BN(0),
+        maxHoldingPerWallet: new BN(0),
+        allowlistMode: { none: {} },
+        allowlistRoot: new Array(32).fill(0),
+        permissionlessComponents: true,
       };
 
       const programState = await pieProgram.state.getProgramState();
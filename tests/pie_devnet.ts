@@ -12,7 +12,7 @@ import {
 import devnetAdmin from "../public/devnet-admin.json";
 import { assert } from "chai";
 import {
-  BasketComponent,
+  CreateBasketComponentArgs,
   CreateBasketArgs,
   PieProgram,
 } from "../sdk/pie-program";
@@ -133,18 +133,21 @@ describe("pie", () => {
   });
 
   it("Create Basket", async () => {
-    const components: BasketComponent[] = [
+    const components: CreateBasketComponentArgs[] = [
       {
         mint: new PublicKey(tokensAmm[0].mint),
-        quantityInSysDecimal: new BN(1 * 10 ** 6),
+        quantity: { sysDecimal: [new BN(1 * 10 ** 6)] },
+        targetWeightBp: new BN(0),
       },
       {
         mint: new PublicKey(tokensAmm[1].mint),
-        quantityInSysDecimal: new BN(2 * 10 ** 6),
+        quantity: { sysDecimal: [new BN(2 * 10 ** 6)] },
+        targetWeightBp: new BN(0),
       },
       {
         mint: new PublicKey(tokensAmm[2].mint),
-        quantityInSysDecimal: new BN(3 * 10 ** 6),
+        quantity: { sysDecimal: [new BN(3 * 10 ** 6)] },
+        targetWeightBp: new BN(0),
       },
     ];
 
@@ -156,6 +159,14 @@ describe("pie", () => {
       rebalancer: admin.publicKey,
       isComponentFixed: false,
       creatorFeeBp: new BN(100),
+      managementFeeBp: new BN(0),
+      performanceFeeBp: new BN(0),
+      weightToleranceBp: new BN(0),
+      maxSupply: new BN(0),
+      maxHoldingPerWallet: new BN(0),
+      allowlistMode: { none: {} },
+      allowlistRoot: new Array(32).fill(0),
+      permissionlessComponents: true,
     };
 
     const programState = await pieProgram.state.getProgramState();
@@ -12,7 +12,7 @@ import {
 import devnetAdmin from "../public/devnet-admin.json";
 import { assert } from "chai";
 import {
-  BasketComponent,
+  CreateBasketComponentArgs,
   CreateBasketArgs,
   PieProgram,
 } from "../sdk/pie-program";
@@ -131,10 +131,11 @@ describe("pie", () => {
   });
 
   it("Create Basket CLMM", async () => {
-    const components: BasketComponent[] = [
+    const components: CreateBasketComponentArgs[] = [
       {
         mint: new PublicKey(tokensClmm[0].mint),
-        quantityInSysDecimal: new BN(1 * 10 ** 6),
+        quantity: { sysDecimal: [new BN(1 * 10 ** 6)] },
+        targetWeightBp: new BN(0),
       },
     ];
 
@@ -145,6 +146,14 @@ describe("pie", () => {
       components: components,
       rebalancer: admin.publicKey,
       isComponentFixed: false,
+      managementFeeBp: new BN(0),
+      performanceFeeBp: new BN(0),
+      weightToleranceBp: new BN(0),
+      maxSupply: new BN(0),
+      maxHoldingPerWallet: new BN(0),
+      allowlistMode: { none: {} },
+      allowlistRoot: new Array(32).fill(0),
+      permissionlessComponents: true,
     };
 
     const programState = await pieProgram.getProgramState();
@@ -15,7 +15,7 @@ import qaAdmin from "../.config/solana/qa-admin.json";
 import qaRebalancer from "../.config/solana/qa-rebalancer.json";
 import { assert } from "chai";
 import {
-  BasketComponent,
+  CreateBasketComponentArgs,
   CreateBasketArgs,
   PieProgram,
   BasketState,
@@ -241,10 +241,13 @@ describe("pie", () => {
   });
 
   it.skip("Create Basket", async () => {
-    const components: BasketComponent[] = sampleTokenMints.map((token) => ({
-      mint: new PublicKey(token.mint),
-      quantityInSysDecimal: new BN(1 * 10 ** 6),
-    }));
+    const components: CreateBasketComponentArgs[] = sampleTokenMints.map(
+      (token) => ({
+        mint: new PublicKey(token.mint),
+        quantity: { sysDecimal: [new BN(1 * 10 ** 6)] },
+        targetWeightBp: new BN(0),
+      })
+    );
 
     const createBasketArgs: CreateBasketArgs = {
       name: "Test Basket",
@@ -254,6 +257,14 @@ describe("pie", () => {
       rebalancer: rebalancer.publicKey,
       rebalanceType: { dynamic: {} },
       creatorFeeBp: new BN(100),
+      managementFeeBp: new BN(0),
+      performanceFeeBp: new BN(0),
+      weightToleranceBp: new BN(0),
+      maxSupply: new BN(0),
+      maxHoldingPerWallet: new BN(0),
+      allowlistMode: { none: {} },
+      allowlistRoot: new Array(32).fill(0),
+      permissionlessComponents: true,
     };
 
     const programState = await pieProgram.state.getProgramState();
@@ -315,6 +326,14 @@ describe("pie", () => {
       rebalancer: rebalancer.publicKey,
       rebalanceType: { dynamic: {} },
       creatorFeeBp: new BN(50),
+      managementFeeBp: new BN(0),
+      performanceFeeBp: new BN(0),
+      weightToleranceBp: new BN(0),
+      maxSupply: new BN(0),
+      maxHoldingPerWallet: new BN(0),
+      allowlistMode: { none: {} },
+      allowlistRoot: new Array(32).fill(0),
+      permissionlessComponents: true,
     };
 
     const programState = await pieProgram.state.getProgramState();